@@ -0,0 +1,130 @@
+use std::ops::{Add, Sub, Mul, Div, Rem};
+
+/// Performs addition that returns `None` instead of wrapping around on overflow.
+pub trait CheckedAdd: Sized + Add<Self, Output = Self> {
+    /// Adds two numbers, checking for overflow. If overflow happens, `None` is returned.
+    fn checked_add(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs subtraction that returns `None` instead of wrapping around on underflow.
+pub trait CheckedSub: Sized + Sub<Self, Output = Self> {
+    /// Subtracts two numbers, checking for underflow. If underflow happens, `None` is returned.
+    fn checked_sub(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs multiplication that returns `None` instead of wrapping around on underflow or
+/// overflow.
+pub trait CheckedMul: Sized + Mul<Self, Output = Self> {
+    /// Multiplies two numbers, checking for underflow or overflow. If underflow or overflow
+    /// happens, `None` is returned.
+    fn checked_mul(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs division that returns `None` instead of panicking on division by zero and instead of
+/// wrapping around on underflow and overflow.
+pub trait CheckedDiv: Sized + Div<Self, Output = Self> {
+    /// Divides two numbers, checking for underflow, overflow and division by zero. If any of
+    /// these happens, `None` is returned.
+    fn checked_div(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs an integral remainder that returns `None` instead of panicking on division by zero
+/// and instead of wrapping around on underflow and overflow.
+pub trait CheckedRem: Sized + Rem<Self, Output = Self> {
+    /// Finds the remainder of dividing two numbers, checking for underflow, overflow and
+    /// division by zero. If any of these happens, `None` is returned.
+    fn checked_rem(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs negation that returns `None` if the result can't be represented.
+pub trait CheckedNeg: Sized {
+    /// Negates a number, returning `None` for results that can't be represented,
+    /// like signed `::MIN` values or any nonzero unsigned value.
+    fn checked_neg(&self) -> Option<Self>;
+}
+
+/// Performs a left shift that returns `None` on shifts larger than or equal to
+/// the type width.
+pub trait CheckedShl: Sized {
+    /// Checked shift left. Computes `self << rhs`,
+    /// returning `None` if `rhs` is larger than or equal to the number of bits in `self`.
+    fn checked_shl(&self, rhs: u32) -> Option<Self>;
+}
+
+/// Performs a right shift that returns `None` on shifts larger than or equal to
+/// the type width.
+pub trait CheckedShr: Sized {
+    /// Checked shift right. Computes `self >> rhs`,
+    /// returning `None` if `rhs` is larger than or equal to the number of bits in `self`.
+    fn checked_shr(&self, rhs: u32) -> Option<Self>;
+}
+
+macro_rules! impl_checked_binop {
+    ($trait_name:ident, $method:ident, $($t:ty)*) => {
+        $(
+            impl $trait_name for $t {
+                #[inline]
+                fn $method(&self, v: &$t) -> Option<$t> {
+                    <$t>::$method(*self, *v)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_checked_shift {
+    ($trait_name:ident, $method:ident, $($t:ty)*) => {
+        $(
+            impl $trait_name for $t {
+                #[inline]
+                fn $method(&self, rhs: u32) -> Option<$t> {
+                    <$t>::$method(*self, rhs)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_checked_neg {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedNeg for $t {
+                #[inline]
+                fn checked_neg(&self) -> Option<$t> {
+                    <$t>::checked_neg(*self)
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_binop!(CheckedAdd, checked_add, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_binop!(CheckedSub, checked_sub, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_binop!(CheckedMul, checked_mul, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_binop!(CheckedDiv, checked_div, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_binop!(CheckedRem, checked_rem, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_neg!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_shift!(CheckedShl, checked_shl, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_checked_shift!(CheckedShr, checked_shr, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+#[test]
+fn test_checked_ops() {
+    fn sum<T: CheckedAdd + Copy>(xs: &[T], zero: T) -> Option<T> {
+        xs.iter().try_fold(zero, |acc, x| acc.checked_add(x))
+    }
+
+    assert_eq!(sum(&[100u8, 100, 55], 0), Some(255));
+    assert_eq!(sum(&[100u8, 100, 56], 0), None);
+    assert_eq!(CheckedSub::checked_sub(&i8::MIN, &1), None);
+    assert_eq!(CheckedMul::checked_mul(&-64i8, &2), Some(-128));
+    assert_eq!(CheckedDiv::checked_div(&i32::MIN, &-1), None);
+    assert_eq!(CheckedRem::checked_rem(&7u32, &0), None);
+    assert_eq!(CheckedRem::checked_rem(&-7i32, &3), Some(-1));
+    assert_eq!(CheckedNeg::checked_neg(&i16::MIN), None);
+    assert_eq!(CheckedNeg::checked_neg(&0u16), Some(0));
+    assert_eq!(CheckedNeg::checked_neg(&1u16), None);
+    assert_eq!(CheckedShl::checked_shl(&1u64, 63), Some(1 << 63));
+    assert_eq!(CheckedShl::checked_shl(&1u64, 64), None);
+    assert_eq!(CheckedShr::checked_shr(&-8isize, 2), Some(-2));
+    assert_eq!(CheckedShr::checked_shr(&8u8, 8), None);
+}
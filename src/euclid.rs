@@ -0,0 +1,125 @@
+use std::ops::{Div, Rem};
+
+/// Euclidean division, whose remainder is never negative.
+pub trait Euclid: Sized + Div<Self, Output = Self> + Rem<Self, Output = Self> {
+    /// Calculates the quotient of Euclidean division, such that
+    /// `self == v * self.div_euclid(v) + self.rem_euclid(v)`.
+    ///
+    /// # Panics
+    ///
+    /// For integers, this function will panic if `v` is zero or if the division overflows.
+    fn div_euclid(&self, v: &Self) -> Self;
+
+    /// Calculates the least non-negative remainder of `self (mod v)`.
+    ///
+    /// # Panics
+    ///
+    /// For integers, this function will panic if `v` is zero or if the division overflows.
+    fn rem_euclid(&self, v: &Self) -> Self;
+}
+
+/// Performs Euclidean division that returns `None` instead of panicking on division by zero
+/// and instead of wrapping around on overflow.
+pub trait CheckedEuclid: Euclid {
+    /// Performs Euclidean division, returning `None` on division by zero or overflow.
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self>;
+
+    /// Finds the Euclidean remainder, returning `None` on division by zero or overflow.
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self>;
+}
+
+/// Performs Euclidean division that wraps around on overflow.
+pub trait WrappingEuclid: Euclid {
+    /// Performs Euclidean division, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `v` is zero.
+    fn wrapping_div_euclid(&self, v: &Self) -> Self;
+
+    /// Finds the Euclidean remainder, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `v` is zero.
+    fn wrapping_rem_euclid(&self, v: &Self) -> Self;
+}
+
+macro_rules! impl_euclid {
+    ($($t:ty)*) => {
+        $(
+            impl Euclid for $t {
+                #[inline]
+                fn div_euclid(&self, v: &Self) -> Self {
+                    <$t>::div_euclid(*self, *v)
+                }
+
+                #[inline]
+                fn rem_euclid(&self, v: &Self) -> Self {
+                    <$t>::rem_euclid(*self, *v)
+                }
+            }
+        )*
+    }
+}
+
+impl_euclid!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+macro_rules! impl_euclid_int {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedEuclid for $t {
+                #[inline]
+                fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+                    <$t>::checked_div_euclid(*self, *v)
+                }
+
+                #[inline]
+                fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+                    <$t>::checked_rem_euclid(*self, *v)
+                }
+            }
+
+            impl WrappingEuclid for $t {
+                #[inline]
+                fn wrapping_div_euclid(&self, v: &Self) -> Self {
+                    <$t>::wrapping_div_euclid(*self, *v)
+                }
+
+                #[inline]
+                fn wrapping_rem_euclid(&self, v: &Self) -> Self {
+                    <$t>::wrapping_rem_euclid(*self, *v)
+                }
+            }
+        )*
+    }
+}
+
+impl_euclid_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+#[test]
+fn test_euclid() {
+    fn check<T: Euclid + Copy + PartialEq + ::std::fmt::Debug>(x: T, v: T, q: T, r: T) {
+        assert_eq!(Euclid::div_euclid(&x, &v), q);
+        assert_eq!(Euclid::rem_euclid(&x, &v), r);
+    }
+
+    check(7i32, 4, 1, 3);
+    check(-7i32, 4, -2, 1);
+    check(7i32, -4, -1, 3);
+    check(-7i32, -4, 2, 1);
+    check(7u8, 4, 1, 3);
+    check(-7.5f64, 2.0, -4.0, 0.5);
+    check(7.5f32, -2.0, -3.0, 1.5);
+}
+
+#[test]
+fn test_checked_wrapping_euclid() {
+    assert_eq!(CheckedEuclid::checked_div_euclid(&-7i8, &2), Some(-4));
+    assert_eq!(CheckedEuclid::checked_div_euclid(&i8::MIN, &-1), None);
+    assert_eq!(CheckedEuclid::checked_rem_euclid(&i8::MIN, &-1), None);
+    assert_eq!(CheckedEuclid::checked_rem_euclid(&5u64, &0), None);
+    assert_eq!(WrappingEuclid::wrapping_div_euclid(&i16::MIN, &-1), i16::MIN);
+    assert_eq!(WrappingEuclid::wrapping_rem_euclid(&i16::MIN, &-1), 0);
+    assert_eq!(WrappingEuclid::wrapping_rem_euclid(&-1i128, &3), 2);
+}
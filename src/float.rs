@@ -1,14 +1,11 @@
 
 use std::{f32, f64};
 use std::num::FpCategory;
-use std::ops::{Add, Sub, Mul, Div, Rem};
 
-use ::{Zero, One, Signed};
+use ::{Bounded, Num, Signed};
 
 /// Float numbers.
-pub trait Float: Copy + Clone + PartialOrd + PartialEq +
-               Zero + One + Signed + Add<Output = Self> + Sub<Output = Self> +
-               Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+pub trait Float: Copy + Clone + PartialOrd + PartialEq + Num + Bounded + Signed
 {
     /// Returns the `NaN` value.
     fn nan() -> Self;
@@ -22,15 +19,9 @@ pub trait Float: Copy + Clone + PartialOrd + PartialEq +
     /// Returns `-0.0`.
     fn neg_zero() -> Self;
 
-    /// Returns the smallest value that can be represented by this numeric type.
-    fn min_value() -> Self;
-
     /// Returns the smallest positive, normalized value that this type can represent.
     fn min_positive_value() -> Self;
 
-    /// Returns the largest value that can be represented by this numeric type.
-    fn max_value() -> Self;
-
     /// Returns `true` if this value is `NaN` and false otherwise.
     fn is_nan(self) -> bool;
 
@@ -58,18 +49,15 @@ pub trait Float: Copy + Clone + PartialOrd + PartialEq +
     /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
     fn round(self) -> Self;
 
+    /// Returns the nearest integer to a number. Round half-way cases to the even integer.
+    fn round_ties_even(self) -> Self;
+
     /// Return the integer part of a number.
     fn trunc(self) -> Self;
 
     /// Returns the fractional part of a number.
     fn fract(self) -> Self;
 
-    /// Returns `true` if `self` is positive, including `+0.0` and `Float::infinity()`.
-    fn is_sign_positive(self) -> bool;
-
-    /// Returns `true` if `self` is negative, including `-0.0` and `Float::neg_infinity()`.
-    fn is_sign_negative(self) -> bool;
-
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
     /// This produces a more accurate result with better performance than
     /// a separate multiplication operation followed by an add.
@@ -202,18 +190,10 @@ macro_rules! impl_float {
                 -0.0
             }
 
-            fn min_value() -> Self {
-                $t::MIN
-            }
-
             fn min_positive_value() -> Self {
                 $t::MIN_POSITIVE
             }
 
-            fn max_value() -> Self {
-                $t::MAX
-            }
-
             fn is_nan(self) -> bool {
                 <$t>::is_nan(self)
             }
@@ -246,6 +226,10 @@ macro_rules! impl_float {
                 <$t>::round(self)
             }
 
+            fn round_ties_even(self) -> Self {
+                <$t>::round_ties_even(self)
+            }
+
             fn trunc(self) -> Self {
                 <$t>::trunc(self)
             }
@@ -254,14 +238,6 @@ macro_rules! impl_float {
                 <$t>::fract(self)
             }
 
-            fn is_sign_positive(self) -> bool {
-                <$t>::is_sign_positive(self)
-            }
-
-            fn is_sign_negative(self) -> bool {
-                <$t>::is_sign_negative(self)
-            }
-
             fn mul_add(self, a: Self, b: Self) -> Self {
                 <$t>::mul_add(self, a, b)
             }
@@ -314,6 +290,7 @@ macro_rules! impl_float {
                 <$t>::min(self, other)
             }
 
+            #[allow(deprecated)]
             fn abs_sub(self, other: Self) -> Self {
                 <$t>::abs_sub(self, other)
             }
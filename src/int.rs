@@ -1,24 +1,35 @@
 
-use std::ops::{Add, Sub, Mul, Div, Rem, Not, BitAnd, BitOr, BitXor, Shl, Shr};
-use std::num::ParseIntError;
+use std::ops::{Not, BitAnd, BitOr, BitXor, Shl, Shr};
 
-use ::{Zero, One};
+use ::{Zero, Bounded, Num, Signed, Unsigned};
+use ::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem, CheckedNeg, CheckedShl, CheckedShr};
 
 /// Signed and unsigned integers.
-pub trait Int: Copy + Clone + PartialOrd + PartialEq +
-               Zero + One + Add<Output = Self> + Sub<Output = Self> +
-               Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self> +
+pub trait Int: Copy + Clone + PartialOrd + PartialEq + Num + Bounded +
                Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self> +
-               BitXor<Output = Self> + Shl<usize, Output = Self> + Shr<usize, Output = Self>
+               BitXor<Output = Self> + Shl<usize, Output = Self> + Shr<usize, Output = Self> +
+               CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem + CheckedNeg +
+               CheckedShl + CheckedShr
 {
-    /// Returns the smallest value that can be represented by this numeric type.
-    fn min_value() -> Self;
+    /// The unsigned integer type of the same width, e.g. `u32` for `i32` and `u32`.
+    type Unsigned: UnsignedInt;
 
-    /// Returns the largest value that can be represented by this numeric type.
-    fn max_value() -> Self;
+    /// The signed integer type of the same width, e.g. `i32` for `i32` and `u32`.
+    type Signed: Int + Signed;
 
-    /// Converts a string slice in a given base to an integer.
-    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+    /// Reinterprets the bits of `self` as the unsigned integer type of the same width.
+    fn cast_unsigned(self) -> Self::Unsigned;
+
+    /// Reinterprets the bits of `self` as the signed integer type of the same width.
+    fn cast_signed(self) -> Self::Signed;
+
+    /// Computes the absolute value of `self` as the unsigned integer type of the same width.
+    /// This never overflows, even for `::MIN` of signed integers.
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// Computes the absolute difference between `self` and `other`
+    /// as the unsigned integer type of the same width. This never overflows.
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
 
     /// Returns the number of ones in the binary representation of `self`.
     fn count_ones(self) -> u32;
@@ -55,22 +66,6 @@ pub trait Int: Copy + Clone + PartialOrd + PartialEq +
     /// Converts `self` to little endian from the target's endianness.
     fn to_le(self) -> Self;
 
-    /// Checked integer addition. Computes `self + other`,
-    /// returning `None` if overflow occurred.
-    fn checked_add(self, other: Self) -> Option<Self>;
-
-    /// Checked integer subtraction. Computes `self - other`,
-    /// returning `None` if underflow occurred.
-    fn checked_sub(self, other: Self) -> Option<Self>;
-
-    /// Checked integer multiplication. Computes `self * other`,
-    /// returning `None` if underflow or overflow occurred.
-    fn checked_mul(self, other: Self) -> Option<Self>;
-
-    /// Checked integer division. Computes `self / other`,
-    /// returning `None` if `other == 0` or the operation results in underflow or overflow.
-    fn checked_div(self, other: Self) -> Option<Self>;
-
     /// Saturating integer addition. Computes `self + other`,
     /// saturating at the numeric bounds instead of overflowing.
     fn saturating_add(self, other: Self) -> Self;
@@ -79,6 +74,18 @@ pub trait Int: Copy + Clone + PartialOrd + PartialEq +
     /// saturating at the numeric bounds instead of overflowing.
     fn saturating_sub(self, other: Self) -> Self;
 
+    /// Saturating integer multiplication. Computes `self * other`,
+    /// saturating at the numeric bounds instead of overflowing.
+    fn saturating_mul(self, other: Self) -> Self;
+
+    /// Saturating integer division. Computes `self / other`,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero.
+    fn saturating_div(self, other: Self) -> Self;
+
     /// Wrapping (modular) addition. Computes `self + other`,
     /// wrapping around at the boundary of the type.
     fn wrapping_add(self, rhs: Self) -> Self;
@@ -113,24 +120,115 @@ pub trait Int: Copy + Clone + PartialOrd + PartialEq +
     /// cause the shift to exceed the bitwidth of the type.
     fn wrapping_shr(self, rhs: u32) -> Self;
 
+    /// Calculates `self + rhs`. Returns a tuple of the addition along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    /// Calculates `self - rhs`. Returns a tuple of the subtraction along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+
+    /// Calculates `self * rhs`. Returns a tuple of the multiplication along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+
+    /// Negates `self`, overflowing if this is equal to the minimum value.
+    /// Returns a tuple of the negated version of `self` along with a boolean
+    /// indicating whether an overflow happened. For unsigned integers, any nonzero
+    /// value overflows.
+    fn overflowing_neg(self) -> (Self, bool);
+
+    /// Shifts `self` left by `rhs` bits. Returns a tuple of the shifted version of `self`
+    /// along with a boolean indicating whether the shift value was larger than or equal to
+    /// the number of bits. If the shift value is too large, then value is masked
+    /// and this value is then used to perform the shift.
+    fn overflowing_shl(self, rhs: u32) -> (Self, bool);
+
+    /// Shifts `self` right by `rhs` bits. Returns a tuple of the shifted version of `self`
+    /// along with a boolean indicating whether the shift value was larger than or equal to
+    /// the number of bits. If the shift value is too large, then value is masked
+    /// and this value is then used to perform the shift.
+    fn overflowing_shr(self, rhs: u32) -> (Self, bool);
+
     /// Raises self to the power of `exp`, using exponentiation by squaring.
     fn pow(self, exp: u32) -> Self;
+
+    /// Checked exponentiation. Computes `self.pow(exp)`,
+    /// returning `None` if overflow occurred.
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+
+    /// Saturating exponentiation. Computes `self.pow(exp)`,
+    /// saturating at the numeric bounds instead of overflowing.
+    fn saturating_pow(self, exp: u32) -> Self;
+
+    /// Raises `self` to the power of `exp`. Returns a tuple of the exponentiation
+    /// along with a boolean indicating whether an overflow happened.
+    fn overflowing_pow(self, exp: u32) -> (Self, bool);
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is less than or equal to zero,
+    /// or if `base` is less than 2.
+    fn ilog(self, base: Self) -> u32;
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is less than or equal to zero.
+    fn ilog2(self) -> u32;
+
+    /// Returns the base 10 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is less than or equal to zero.
+    fn ilog10(self) -> u32;
+
+    /// Checked logarithm. Computes `self.ilog(base)`, returning `None`
+    /// if `self` is less than or equal to zero, or if `base` is less than 2.
+    fn checked_ilog(self, base: Self) -> Option<u32>;
+
+    /// Checked base 2 logarithm. Computes `self.ilog2()`, returning `None`
+    /// if `self` is less than or equal to zero.
+    fn checked_ilog2(self) -> Option<u32>;
+
+    /// Checked base 10 logarithm. Computes `self.ilog10()`, returning `None`
+    /// if `self` is less than or equal to zero.
+    fn checked_ilog10(self) -> Option<u32>;
 }
 
 macro_rules! impl_int {
-    ($($t:ty)*) => {
+    ($($t:ident($s:ident, $u:ident))*) => {
         $(
             impl Int for $t {
-                fn min_value() -> Self {
-                    <$t>::min_value()
+                type Unsigned = $u;
+
+                type Signed = $s;
+
+                fn cast_unsigned(self) -> $u {
+                    self as $u
                 }
 
-                fn max_value() -> Self {
-                    <$t>::max_value()
+                fn cast_signed(self) -> $s {
+                    self as $s
                 }
 
-                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-                    <$t>::from_str_radix(src, radix)
+                fn unsigned_abs(self) -> $u {
+                    if self < Self::zero() {
+                        (self as $u).wrapping_neg()
+                    } else {
+                        self as $u
+                    }
+                }
+
+                fn abs_diff(self, other: Self) -> $u {
+                    <$t>::abs_diff(self, other)
                 }
 
                 fn count_ones(self) -> u32 {
@@ -177,22 +275,6 @@ macro_rules! impl_int {
                     <$t>::to_le(self)
                 }
 
-                fn checked_add(self, other: Self) -> Option<Self> {
-                    <$t>::checked_add(self, other)
-                }
-
-                fn checked_sub(self, other: Self) -> Option<Self> {
-                    <$t>::checked_sub(self, other)
-                }
-
-                fn checked_mul(self, other: Self) -> Option<Self> {
-                    <$t>::checked_mul(self, other)
-                }
-
-                fn checked_div(self, other: Self) -> Option<Self> {
-                    <$t>::checked_div(self, other)
-                }
-
                 fn saturating_add(self, other: Self) -> Self {
                     <$t>::saturating_add(self, other)
                 }
@@ -201,6 +283,14 @@ macro_rules! impl_int {
                     <$t>::saturating_sub(self, other)
                 }
 
+                fn saturating_mul(self, other: Self) -> Self {
+                    <$t>::saturating_mul(self, other)
+                }
+
+                fn saturating_div(self, other: Self) -> Self {
+                    <$t>::saturating_div(self, other)
+                }
+
                 fn wrapping_add(self, rhs: Self) -> Self {
                     <$t>::wrapping_add(self, rhs)
                 }
@@ -233,18 +323,79 @@ macro_rules! impl_int {
                     <$t>::wrapping_shr(self, rhs)
                 }
 
+                fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                    <$t>::overflowing_add(self, rhs)
+                }
+
+                fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                    <$t>::overflowing_sub(self, rhs)
+                }
+
+                fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                    <$t>::overflowing_mul(self, rhs)
+                }
+
+                fn overflowing_neg(self) -> (Self, bool) {
+                    <$t>::overflowing_neg(self)
+                }
+
+                fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+                    <$t>::overflowing_shl(self, rhs)
+                }
+
+                fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+                    <$t>::overflowing_shr(self, rhs)
+                }
+
                 fn pow(self, exp: u32) -> Self {
                     <$t>::pow(self, exp)
                 }
+
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exp)
+                }
+
+                fn saturating_pow(self, exp: u32) -> Self {
+                    <$t>::saturating_pow(self, exp)
+                }
+
+                fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                    <$t>::overflowing_pow(self, exp)
+                }
+
+                fn ilog(self, base: Self) -> u32 {
+                    <$t>::ilog(self, base)
+                }
+
+                fn ilog2(self) -> u32 {
+                    <$t>::ilog2(self)
+                }
+
+                fn ilog10(self) -> u32 {
+                    <$t>::ilog10(self)
+                }
+
+                fn checked_ilog(self, base: Self) -> Option<u32> {
+                    <$t>::checked_ilog(self, base)
+                }
+
+                fn checked_ilog2(self) -> Option<u32> {
+                    <$t>::checked_ilog2(self)
+                }
+
+                fn checked_ilog10(self) -> Option<u32> {
+                    <$t>::checked_ilog10(self)
+                }
             }
         )*
     }
 }
 
-impl_int!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+impl_int!(u8(i8, u8) u16(i16, u16) u32(i32, u32) u64(i64, u64) u128(i128, u128) usize(isize, usize)
+          i8(i8, u8) i16(i16, u16) i32(i32, u32) i64(i64, u64) i128(i128, u128) isize(isize, usize));
 
 /// Unsigned integers.
-pub trait UnsignedInt: Int {
+pub trait UnsignedInt: Int + Unsigned {
     /// Returns true if and only if `self == 2^k` for some `k`.
     fn is_power_of_two(self) -> bool;
 
@@ -278,4 +429,97 @@ macro_rules! impl_unsigned_int {
     }
 }
 
-impl_unsigned_int!(u8 u16 u32 u64 usize);
+impl_unsigned_int!(u8 u16 u32 u64 u128 usize);
+
+#[test]
+fn test_overflowing_ops() {
+    fn add_words<T: Int>(a: &[T], b: &[T]) -> (Vec<T>, bool) {
+        let mut carry = false;
+        let sum = a.iter().zip(b).map(|(&x, &y)| {
+            let (s, c1) = x.overflowing_add(y);
+            let (s, c2) = s.overflowing_add(if carry { T::one() } else { T::zero() });
+            carry = c1 || c2;
+            s
+        }).collect();
+        (sum, carry)
+    }
+
+    assert_eq!(add_words(&[255u8, 1], &[1, 254]), (vec![0, 0], true));
+    assert_eq!(add_words(&[255u8, 1], &[1, 253]), (vec![0, 255], false));
+    assert_eq!(Int::overflowing_sub(0u32, 1), (u32::MAX, true));
+    assert_eq!(Int::overflowing_mul(-128i8, -1), (-128, true));
+    assert_eq!(Int::overflowing_neg(i64::MIN), (i64::MIN, true));
+    assert_eq!(Int::overflowing_neg(1usize), (usize::MAX, true));
+    assert_eq!(Int::overflowing_shl(1u16, 17), (2, true));
+    assert_eq!(Int::overflowing_shr(-4i32, 1), (-2, false));
+    assert_eq!(Int::overflowing_pow(3u8, 5), (243, false));
+    assert_eq!(Int::overflowing_pow(3u8, 6), (217, true));
+}
+
+#[test]
+fn test_saturating_ops() {
+    fn gain<T: Int>(samples: &[T], factor: T) -> Vec<T> {
+        samples.iter().map(|&x| x.saturating_mul(factor)).collect()
+    }
+
+    assert_eq!(gain(&[-100i16, 10, 20000], 3), vec![-300, 30, i16::MAX]);
+    assert_eq!(gain(&[-20000i16, 0], 2), vec![i16::MIN, 0]);
+    assert_eq!(Int::saturating_mul(200u8, 2), u8::MAX);
+    assert_eq!(Int::saturating_div(i32::MIN, -1), i32::MAX);
+    assert_eq!(Int::saturating_div(7u32, 2), 3);
+    assert_eq!(Int::saturating_pow(-3i8, 5), i8::MIN);
+    assert_eq!(Int::saturating_pow(2u64, 64), u64::MAX);
+    assert_eq!(Int::saturating_pow(2u64, 10), 1024);
+}
+
+#[test]
+fn test_signed_unsigned_pairing() {
+    fn abs_as_unsigned<T: Int>(x: T) -> T::Unsigned {
+        x.unsigned_abs()
+    }
+
+    assert_eq!(abs_as_unsigned(-128i8), 128u8);
+    assert_eq!(abs_as_unsigned(i32::MIN), 2147483648u32);
+    assert_eq!(abs_as_unsigned(200u8), 200u8);
+    assert_eq!(Int::cast_unsigned(-1i16), u16::MAX);
+    assert_eq!(Int::cast_signed(u64::MAX), -1i64);
+    assert_eq!(Int::cast_signed(5usize), 5isize);
+}
+
+#[test]
+fn test_ilog() {
+    fn check<T: Int>(x: T, base: T, log: u32, log2: u32, log10: u32) {
+        assert_eq!(x.ilog(base), log);
+        assert_eq!(x.ilog2(), log2);
+        assert_eq!(x.ilog10(), log10);
+    }
+
+    check(1u8, 2, 0, 0, 0);
+    check(255u8, 3, 5, 7, 2);
+    check(1000i16, 10, 3, 9, 3);
+    check(u64::MAX, 16, 15, 63, 19);
+    check(i128::MAX, 7, 45, 126, 38);
+    check(usize::MAX as u32 as usize, 2, 31, 31, 9);
+
+    assert_eq!(Int::checked_ilog(0u32, 10), None);
+    assert_eq!(Int::checked_ilog(10u32, 1), None);
+    assert_eq!(Int::checked_ilog(-8i32, 2), None);
+    assert_eq!(Int::checked_ilog2(-1i64), None);
+    assert_eq!(Int::checked_ilog2(0u16), None);
+    assert_eq!(Int::checked_ilog10(99_999i32), Some(4));
+    assert_eq!(Int::checked_ilog10(i8::MIN), None);
+}
+
+#[test]
+fn test_generic_shifts() {
+    fn double<T: Int>(x: T) -> T { x << 1 }
+    fn halve<T: Int>(x: T) -> T { x >> 1 }
+    fn quadruple<T: Int>(x: T) -> Option<T> { x.checked_shl(2) }
+
+    assert_eq!(double(3u8), 6);
+    assert_eq!(double(-5i64), -10);
+    assert_eq!(halve(u128::MAX), u128::MAX / 2);
+    assert_eq!(halve(-8i16), -4);
+    assert_eq!(quadruple(3u32), Some(12));
+    assert_eq!(quadruple(1u8 << 7), Some(0));
+}
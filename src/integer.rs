@@ -0,0 +1,235 @@
+use std::mem;
+
+use ::{Int, UnsignedInt, CastFrom};
+
+/// Integers with number-theoretic operations.
+pub trait Integer: Int {
+    /// Floored integer division, rounding toward negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero or if the division overflows.
+    fn div_floor(self, other: Self) -> Self {
+        let (d, r) = self.div_rem(other);
+        if r != Self::zero() && (r < Self::zero()) != (other < Self::zero()) {
+            d - Self::one()
+        } else {
+            d
+        }
+    }
+
+    /// Floored integer modulo, whose result has the same sign as `other`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero or if the division overflows.
+    fn mod_floor(self, other: Self) -> Self {
+        let r = self % other;
+        if r != Self::zero() && (r < Self::zero()) != (other < Self::zero()) {
+            r + other
+        } else {
+            r
+        }
+    }
+
+    /// Ceiled integer division, rounding toward positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero or if the division overflows.
+    fn div_ceil(self, other: Self) -> Self {
+        let (d, r) = self.div_rem(other);
+        if r != Self::zero() && (r > Self::zero()) == (other > Self::zero()) {
+            d + Self::one()
+        } else {
+            d
+        }
+    }
+
+    /// Simultaneous truncated integer division and remainder, `(self / other, self % other)`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero or if the division overflows.
+    fn div_rem(self, other: Self) -> (Self, Self) {
+        (self / other, self % other)
+    }
+
+    /// Simultaneous floored integer division and modulo,
+    /// `(self.div_floor(other), self.mod_floor(other))`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is zero or if the division overflows.
+    fn div_mod_floor(self, other: Self) -> (Self, Self) {
+        (self.div_floor(other), self.mod_floor(other))
+    }
+
+    /// Checked floored integer division. Computes `self.div_floor(other)`,
+    /// returning `None` if `other == 0` or the division results in overflow.
+    fn checked_div_floor(self, other: Self) -> Option<Self> {
+        self.checked_div(&other).map(|_| self.div_floor(other))
+    }
+
+    /// Checked floored integer modulo. Computes `self.mod_floor(other)`,
+    /// returning `None` if `other == 0` or the division results in overflow.
+    fn checked_mod_floor(self, other: Self) -> Option<Self> {
+        self.checked_rem(&other).map(|_| self.mod_floor(other))
+    }
+
+    /// Checked ceiled integer division. Computes `self.div_ceil(other)`,
+    /// returning `None` if `other == 0` or the division results in overflow.
+    fn checked_div_ceil(self, other: Self) -> Option<Self> {
+        self.checked_div(&other).map(|_| self.div_ceil(other))
+    }
+
+    /// Greatest common divisor, computed with the binary (Stein's) algorithm.
+    /// The result is always non-negative, and `gcd(0, 0) == 0`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows, i.e. if it is `2^(n - 1)`
+    /// for a signed integer of `n` bits, as for `gcd(::MIN, 0)`.
+    fn gcd(self, other: Self) -> Self {
+        self.checked_gcd(other).expect("attempt to compute gcd with overflow")
+    }
+
+    /// Checked greatest common divisor. Computes `self.gcd(other)`,
+    /// returning `None` if the result overflows.
+    fn checked_gcd(self, other: Self) -> Option<Self>;
+
+    /// Lowest common multiple. The result is always non-negative,
+    /// and is zero if either operand is zero.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows.
+    fn lcm(self, other: Self) -> Self {
+        self.checked_lcm(other).expect("attempt to compute lcm with overflow")
+    }
+
+    /// Checked lowest common multiple. Computes `self.lcm(other)`,
+    /// returning `None` if the result overflows.
+    fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self == Self::zero() || other == Self::zero() {
+            return Some(Self::zero());
+        }
+        let l = (self / self.checked_gcd(other)?).checked_mul(&other)?;
+        if l < Self::zero() { l.checked_neg() } else { Some(l) }
+    }
+
+    /// Returns `true` if `self` is a multiple of `other`.
+    /// Zero is a multiple of every integer, but only zero is a multiple of zero.
+    fn is_multiple_of(self, other: Self) -> bool {
+        if other == Self::zero() {
+            self == Self::zero()
+        } else {
+            // Only `::MIN % -1` overflows, and `::MIN` is a multiple of `-1`.
+            self.checked_rem(&other).is_none_or(|r| r == Self::zero())
+        }
+    }
+
+    /// Returns `true` if the number is divisible by `2`.
+    fn is_even(self) -> bool {
+        self & Self::one() == Self::zero()
+    }
+
+    /// Returns `true` if the number is not divisible by `2`.
+    fn is_odd(self) -> bool {
+        !self.is_even()
+    }
+}
+
+/// Stein's algorithm for the greatest common divisor.
+fn binary_gcd<U: UnsignedInt>(mut a: U, mut b: U) -> U {
+    if a == U::zero() {
+        return b;
+    }
+    if b == U::zero() {
+        return a;
+    }
+    let shift = (a | b).trailing_zeros() as usize;
+    a = a >> a.trailing_zeros() as usize;
+    loop {
+        b = b >> b.trailing_zeros() as usize;
+        if a > b {
+            mem::swap(&mut a, &mut b);
+        }
+        b = b - a;
+        if b == U::zero() {
+            return a << shift;
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {
+        $(
+            impl Integer for $t {
+                fn checked_gcd(self, other: Self) -> Option<Self> {
+                    let g = binary_gcd(Int::unsigned_abs(self), Int::unsigned_abs(other));
+                    <$t>::cast_from(g)
+                }
+            }
+        )*
+    }
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+#[test]
+fn test_floor_ceil_division() {
+    assert_eq!(Integer::div_floor(7i32, 2), 3);
+    assert_eq!(Integer::div_floor(-7i32, 2), -4);
+    assert_eq!(Integer::div_floor(7i32, -2), -4);
+    assert_eq!(Integer::div_floor(-7i32, -2), 3);
+    assert_eq!(Integer::mod_floor(-7i32, 2), 1);
+    assert_eq!(Integer::mod_floor(7i32, -2), -1);
+    assert_eq!(Integer::mod_floor(-8i32, 2), 0);
+    assert_eq!(Integer::div_ceil(7i64, 2), 4);
+    assert_eq!(Integer::div_ceil(-7i64, 2), -3);
+    assert_eq!(Integer::div_ceil(-7i64, -2), 4);
+    assert_eq!(Integer::div_ceil(7u8, 7), 1);
+    assert_eq!(Integer::div_rem(-7i8, 2), (-3, -1));
+    assert_eq!(Integer::div_mod_floor(-7i8, 2), (-4, 1));
+    assert_eq!(Integer::div_mod_floor(200u8, 7), (28, 4));
+
+    assert_eq!(Integer::checked_div_floor(i16::MIN, -1), None);
+    assert_eq!(Integer::checked_div_floor(5u16, 0), None);
+    assert_eq!(Integer::checked_mod_floor(-5i16, 3), Some(1));
+    assert_eq!(Integer::checked_div_ceil(i128::MIN, -1), None);
+    assert_eq!(Integer::checked_div_ceil(-5isize, 3), Some(-1));
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(Integer::gcd(0u32, 0), 0);
+    assert_eq!(Integer::gcd(48u32, 18), 6);
+    assert_eq!(Integer::gcd(-48i32, 18), 6);
+    assert_eq!(Integer::gcd(0i64, -7), 7);
+    assert_eq!(Integer::gcd(u128::MAX, u128::MAX - 1), 1);
+    assert_eq!(Integer::gcd(i8::MIN, 64), 64);
+    assert_eq!(Integer::checked_gcd(i8::MIN, 0), None);
+    assert_eq!(Integer::checked_gcd(i8::MIN, i8::MIN), None);
+    assert_eq!(Integer::gcd(1u64 << 40, 3u64 << 20), 1 << 20);
+
+    assert_eq!(Integer::lcm(4u8, 6), 12);
+    assert_eq!(Integer::lcm(-4i16, 6), 12);
+    assert_eq!(Integer::lcm(0i16, 6), 0);
+    assert_eq!(Integer::checked_lcm(16u8, 17), None);
+    assert_eq!(Integer::checked_lcm(i32::MIN, 1), None);
+    assert_eq!(Integer::checked_lcm(-64i8, 2), Some(64));
+}
+
+#[test]
+fn test_divisibility() {
+    assert!(Integer::is_multiple_of(0u8, 0));
+    assert!(!Integer::is_multiple_of(3u8, 0));
+    assert!(Integer::is_multiple_of(12i32, -4));
+    assert!(!Integer::is_multiple_of(13i32, 4));
+    assert!(Integer::is_multiple_of(i64::MIN, -1));
+    assert!(Integer::is_even(-2i8));
+    assert!(Integer::is_even(0usize));
+    assert!(Integer::is_odd(i32::MIN + 1));
+    assert!(Integer::is_odd(u128::MAX));
+}
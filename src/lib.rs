@@ -1,15 +1,32 @@
 //! Numeric traits for generic mathematics.
 
+use std::error::Error;
+use std::fmt;
 use std::mem::size_of;
+use std::num::{IntErrorKind, ParseIntError, Wrapping};
+use std::ops::{Add, Sub, Mul, Div, Rem};
 
 /// Reexports.
+pub use checked::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem, CheckedNeg,
+                  CheckedShl, CheckedShr};
 pub use int::{Int, UnsignedInt};
+pub use integer::Integer;
+pub use euclid::{Euclid, CheckedEuclid, WrappingEuclid};
+pub use roots::Roots;
+pub use modular::{ModularOps, ExtendedGcd};
 pub use float::Float;
-pub use signed::Signed;
+pub use signed::{Signed, Unsigned, Sign};
+pub use widening::WideningOps;
 
+mod checked;
 mod int;
+mod integer;
+mod euclid;
+mod roots;
+mod modular;
 mod float;
 mod signed;
+mod widening;
 
 /// Types that have a `zero` value.
 ///
@@ -45,7 +62,7 @@ macro_rules! impl_zero_one_int {
     }
 }
 
-impl_zero_one_int!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+impl_zero_one_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
 
 macro_rules! impl_zero_one_float {
     ($($t:ty)*) => {
@@ -65,14 +82,289 @@ macro_rules! impl_zero_one_float {
 
 impl_zero_one_float!(f32 f64);
 
+/// Numbers which have upper and lower bounds.
+pub trait Bounded {
+    /// Returns the smallest finite number this type can represent.
+    fn min_value() -> Self;
+
+    /// Returns the largest finite number this type can represent.
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty)*) => {
+        $(
+            impl Bounded for $t {
+                #[inline(always)]
+                fn min_value() -> Self { <$t>::MIN }
+
+                #[inline(always)]
+                fn max_value() -> Self { <$t>::MAX }
+            }
+        )*
+    }
+}
+
+impl_bounded!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl<T: Bounded> Bounded for Wrapping<T> {
+    fn min_value() -> Self { Wrapping(T::min_value()) }
+
+    fn max_value() -> Self { Wrapping(T::max_value()) }
+}
+
+impl Bounded for () {
+    fn min_value() -> Self {}
+
+    fn max_value() -> Self {}
+}
+
+macro_rules! impl_bounded_tuple {
+    ($($T:ident)*) => {
+        impl<$($T: Bounded),*> Bounded for ($($T,)*) {
+            fn min_value() -> Self { ($($T::min_value(),)*) }
+
+            fn max_value() -> Self { ($($T::max_value(),)*) }
+        }
+    }
+}
+
+impl_bounded_tuple!(A);
+impl_bounded_tuple!(A B);
+impl_bounded_tuple!(A B C);
+impl_bounded_tuple!(A B C D);
+impl_bounded_tuple!(A B C D E);
+impl_bounded_tuple!(A B C D E F);
+impl_bounded_tuple!(A B C D E F G);
+impl_bounded_tuple!(A B C D E F G H);
+impl_bounded_tuple!(A B C D E F G H I);
+impl_bounded_tuple!(A B C D E F G H I J);
+impl_bounded_tuple!(A B C D E F G H I J K);
+impl_bounded_tuple!(A B C D E F G H I J K L);
+
+/// Base trait for numeric types, bundling the identities and the arithmetic operators.
+pub trait Num: Sized + PartialEq + Zero + One + Add<Output = Self> + Sub<Output = Self> +
+               Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    /// Converts a string slice in a given base to a number.
+    ///
+    /// The radix must lie in the range `[2, 36]`.
+    ///
+    /// For `f32` and `f64`, a radix of 10 accepts everything `str::parse` does,
+    /// including exponents, `inf` and `NaN`. Other radices accept an optional sign,
+    /// followed by digits with an optional radix point.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseNumError>;
+}
+
+/// An error which can be returned when parsing a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNumError {
+    /// The string to parse is empty.
+    Empty,
+    /// The string contains a character which is not a valid digit in the given radix.
+    InvalidDigit,
+    /// The number is too large to be represented by the target type.
+    Overflow,
+    /// The number is too small to be represented by the target type.
+    Underflow,
+}
+
+impl fmt::Display for ParseNumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            ParseNumError::Empty => "cannot parse number from empty string",
+            ParseNumError::InvalidDigit => "invalid digit found in string",
+            ParseNumError::Overflow => "number too large to fit in target type",
+            ParseNumError::Underflow => "number too small to fit in target type",
+        };
+        f.write_str(description)
+    }
+}
+
+impl Error for ParseNumError {}
+
+impl From<ParseIntError> for ParseNumError {
+    fn from(err: ParseIntError) -> ParseNumError {
+        match *err.kind() {
+            IntErrorKind::Empty => ParseNumError::Empty,
+            IntErrorKind::PosOverflow => ParseNumError::Overflow,
+            IntErrorKind::NegOverflow => ParseNumError::Underflow,
+            _ => ParseNumError::InvalidDigit,
+        }
+    }
+}
+
+macro_rules! impl_num_int {
+    ($($t:ty)*) => {
+        $(
+            impl Num for $t {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseNumError> {
+                    <$t>::from_str_radix(src, radix).map_err(ParseNumError::from)
+                }
+            }
+        )*
+    }
+}
+
+impl_num_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_num_float {
+    ($($t:ty)*) => {
+        $(
+            impl Num for $t {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseNumError> {
+                    assert!((2..=36).contains(&radix),
+                            "from_str_radix: radix must lie in the range `[2, 36]` - found {}", radix);
+                    if src.is_empty() {
+                        return Err(ParseNumError::Empty);
+                    }
+                    if radix == 10 {
+                        return src.parse().map_err(|_| ParseNumError::InvalidDigit);
+                    }
+
+                    let (negative, digits) = match src.as_bytes()[0] {
+                        b'-' => (true, &src[1..]),
+                        b'+' => (false, &src[1..]),
+                        _ => (false, src),
+                    };
+                    let (int_part, frac_part) = match digits.find('.') {
+                        Some(i) => (&digits[..i], &digits[i + 1..]),
+                        None => (digits, ""),
+                    };
+                    if int_part.is_empty() && frac_part.is_empty() {
+                        return Err(ParseNumError::InvalidDigit);
+                    }
+
+                    let base = radix as $t;
+                    let mut value: $t = 0.0;
+                    for c in int_part.chars() {
+                        let d = c.to_digit(radix).ok_or(ParseNumError::InvalidDigit)?;
+                        value = value * base + d as $t;
+                    }
+                    let mut factor: $t = 1.0;
+                    for c in frac_part.chars() {
+                        let d = c.to_digit(radix).ok_or(ParseNumError::InvalidDigit)?;
+                        factor /= base;
+                        value += d as $t * factor;
+                    }
+                    Ok(if negative { -value } else { value })
+                }
+            }
+        )*
+    }
+}
+
+impl_num_float!(f32 f64);
+
+/// An error which can be returned when casting between numeric types.
+///
+/// Each variant carries a description of the source value, such as `300_u16`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastError {
+    /// The value is too large to be represented by the target type.
+    Overflow(String),
+    /// The value is too small to be represented by the target type.
+    Underflow(String),
+    /// The value is `NaN`, which the target type cannot represent.
+    NaN(String),
+    /// The value is infinite, which the target type cannot represent.
+    Infinite(String),
+    /// The value is in range, but cannot be represented exactly by the target type.
+    Inexact(String),
+    /// The value is in range, but does not correspond to a valid value of the target type,
+    /// such as a surrogate code point for `char`.
+    Invalid(String),
+}
+
+impl CastError {
+    /// Returns the description of the source value that failed to cast.
+    pub fn value(&self) -> &str {
+        match *self {
+            CastError::Overflow(ref value) |
+            CastError::Underflow(ref value) |
+            CastError::NaN(ref value) |
+            CastError::Infinite(ref value) |
+            CastError::Inexact(ref value) |
+            CastError::Invalid(ref value) => value,
+        }
+    }
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match *self {
+            CastError::Overflow(_) => "value is too large for the target type",
+            CastError::Underflow(_) => "value is too small for the target type",
+            CastError::NaN(_) => "value is not a number",
+            CastError::Infinite(_) => "value is infinite",
+            CastError::Inexact(_) => "value cannot be represented exactly by the target type",
+            CastError::Invalid(_) => "value is not valid for the target type",
+        };
+        write!(f, "cannot cast {}: {}", self.value(), reason)
+    }
+}
+
+impl Error for CastError {}
+
+/// Describes a source value for a `CastError`, e.g. `300_u16`.
+fn describe<T: fmt::Debug>(x: T, type_name: &str) -> String {
+    format!("{:?}_{}", x, type_name)
+}
+
+/// The variant of a `CastError` before the source value is described.
+///
+/// The checks of the primitive casts report this kind, so that `cast_from` never
+/// allocates and only `try_cast_from` pays for the description.
+#[derive(Debug, Clone, Copy)]
+enum CastErrorKind {
+    Overflow,
+    Underflow,
+    NaN,
+    Infinite,
+    Inexact,
+    Invalid,
+}
+
+impl CastErrorKind {
+    fn describe<T: fmt::Debug>(self, x: T, type_name: &str) -> CastError {
+        let value = describe(x, type_name);
+        match self {
+            CastErrorKind::Overflow => CastError::Overflow(value),
+            CastErrorKind::Underflow => CastError::Underflow(value),
+            CastErrorKind::NaN => CastError::NaN(value),
+            CastErrorKind::Infinite => CastError::Infinite(value),
+            CastErrorKind::Inexact => CastError::Inexact(value),
+            CastErrorKind::Invalid => CastError::Invalid(value),
+        }
+    }
+}
+
+/// The checked conversion between two primitive types behind `CastFrom`.
+trait CastCheck<S>: Sized {
+    fn check(x: S) -> Result<Self, CastErrorKind>;
+}
+
+/// The checked conversion between two primitive types behind `ExactCastFrom`.
+trait ExactCastCheck<S>: Sized {
+    fn check_exact(x: S) -> Result<Self, CastErrorKind>;
+}
+
 /// Constructs `Self` from the other type via a conversion.
 pub trait CastFrom<T>: Sized {
+    /// Constructs `Self` from the type `T`, returning the reason of the failure as an error.
+    fn try_cast_from(x: T) -> Result<Self, CastError>;
+
     /// Constructs `Self` from the type `T`.
-    fn cast_from(x: T) -> Option<Self>;
+    fn cast_from(x: T) -> Option<Self> {
+        Self::try_cast_from(x).ok()
+    }
 }
 
 /// Converts `Self` into the other type.
 pub trait CastInto<T> {
+    /// Casts `Self` into the type `T`, returning the reason of the failure as an error.
+    fn try_cast_into(self) -> Result<T, CastError>;
+
     /// Casts `Self` into the type `T`.
     fn cast_into(self) -> Option<T>;
 }
@@ -80,311 +372,924 @@ pub trait CastInto<T> {
 impl<S, T> CastInto<T> for S
     where T: CastFrom<S>
 {
+    fn try_cast_into(self) -> Result<T, CastError> {
+        <T>::try_cast_from(self)
+    }
+
     fn cast_into(self) -> Option<T> {
         <T>::cast_from(self)
     }
 }
 
+/// Implements `CastFrom` on top of a `CastCheck` impl.
+macro_rules! impl_cast_from_check {
+    ($S:ident => $T:ident) => {
+        impl CastFrom<$S> for $T {
+            #[inline]
+            fn try_cast_from(x: $S) -> Result<$T, CastError> {
+                <$T as CastCheck<$S>>::check(x).map_err(|kind| kind.describe(x, stringify!($S)))
+            }
+
+            #[inline]
+            fn cast_from(x: $S) -> Option<$T> {
+                <$T as CastCheck<$S>>::check(x).ok()
+            }
+        }
+    }
+}
+
 macro_rules! impl_cast_same_type {
     ($T:ty) => {
         impl CastFrom<$T> for $T {
-            fn cast_from(x: $T) -> Option<$T> {
-                Some(x)
+            fn try_cast_from(x: $T) -> Result<$T, CastError> {
+                Ok(x)
             }
         }
     }
 }
 
-macro_rules! impl_cast_int_to_int {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                if size_of::<$S>() <= size_of::<$T>() {
-                    Some(x as $T)
-                } else {
-                    let n = x as i64;
-                    let min_value = <$T>::min_value();
-                    let max_value = <$T>::max_value();
-                    if min_value as i64 <= n && n <= max_value as i64 {
-                        Some(x as $T)
+/// Implements `CastFrom` for every pair of the given types.
+///
+/// Each type is tagged with its kind, `signed`, `unsigned` or `float`,
+/// from which the range check of every pair is derived.
+macro_rules! impl_cast_matrix {
+    ($($S:ident($s:ident))*) => {
+        impl_cast_matrix!(@rows [$($S($s))*] [$($S($s))*]);
+    };
+    (@rows [$($S:ident($s:ident))*] $targets:tt) => {
+        $(
+            impl_cast_matrix!(@row $S($s) => $targets);
+        )*
+    };
+    (@row $S:ident($s:ident) => [$($T:ident($t:ident))*]) => {
+        $(
+            impl CastCheck<$S> for $T {
+                #[inline]
+                fn check(x: $S) -> Result<$T, CastErrorKind> {
+                    impl_cast_matrix!(@check x: $S($s) => $T($t))
+                }
+            }
+
+            impl_cast_from_check!($S => $T);
+        )*
+    };
+    (@check $x:ident: $S:ident(signed) => $T:ident(signed)) => {{
+        let min_value = <$T as Bounded>::min_value();
+        let max_value = <$T as Bounded>::max_value();
+        if ($x as i128) < min_value as i128 {
+            Err(CastErrorKind::Underflow)
+        } else if $x as i128 > max_value as i128 {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok($x as $T)
+        }
+    }};
+    (@check $x:ident: $S:ident(signed) => $T:ident(unsigned)) => {{
+        let max_value = <$T as Bounded>::max_value();
+        if $x < 0 {
+            Err(CastErrorKind::Underflow)
+        } else if $x as u128 > max_value as u128 {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok($x as $T)
+        }
+    }};
+    (@check $x:ident: $S:ident(unsigned) => $T:ident(signed)) => {
+        impl_cast_matrix!(@check $x: $S(unsigned) => $T(unsigned))
+    };
+    (@check $x:ident: $S:ident(unsigned) => $T:ident(unsigned)) => {{
+        let max_value = <$T as Bounded>::max_value();
+        if $x as u128 > max_value as u128 {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok($x as $T)
+        }
+    }};
+    (@check $x:ident: $S:ident(signed) => $T:ident(float)) => {
+        impl_cast_matrix!(@check $x: $S(unsigned) => $T(float))
+    };
+    // Only `u128` in `f32` can round past the finite range, and it does so to infinity.
+    (@check $x:ident: $S:ident(unsigned) => $T:ident(float)) => {{
+        let y = $x as $T;
+        if y.is_infinite() {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok(y)
+        }
+    }};
+    // Floats are truncated toward zero, so the accepted range is the open interval
+    // `(MIN - 1, MAX + 1)`. Both `MIN` and `MAX + 1` are powers of two and thus exact in
+    // any float type, whereas `MAX` itself may round up to `MAX + 1` (e.g. `i64::MAX as f64`).
+    (@check $x:ident: $S:ident(float) => $T:ident(signed)) => {{
+        let min_value = <$T as Bounded>::min_value() as $S;
+        let max_value_p1 = -min_value;
+        // If `MIN - 1` is not representable, it rounds to `MIN`
+        // and there are no floats strictly between them.
+        let min_value_m1 = min_value - 1.0;
+        let underflow = if min_value_m1 < min_value {
+            $x <= min_value_m1
+        } else {
+            $x < min_value
+        };
+        if $x.is_nan() {
+            Err(CastErrorKind::NaN)
+        } else if $x.is_infinite() {
+            Err(CastErrorKind::Infinite)
+        } else if underflow {
+            Err(CastErrorKind::Underflow)
+        } else if $x >= max_value_p1 {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok($x as $T)
+        }
+    }};
+    // The accepted range is the open interval `(-1, MAX + 1)`, where `MAX + 1` is a power of two.
+    // It is computed as `2 * (MAX / 2 + 1)` to stay exact, and is infinite for `u128` in `f32`.
+    (@check $x:ident: $S:ident(float) => $T:ident(unsigned)) => {{
+        let max_value_p1 = (<$T as Bounded>::max_value() / 2 + 1) as $S * 2.0;
+        if $x.is_nan() {
+            Err(CastErrorKind::NaN)
+        } else if $x.is_infinite() {
+            Err(CastErrorKind::Infinite)
+        } else if $x <= -1.0 {
+            Err(CastErrorKind::Underflow)
+        } else if $x >= max_value_p1 {
+            Err(CastErrorKind::Overflow)
+        } else {
+            Ok($x as $T)
+        }
+    }};
+    // Widening is always exact. Narrowing rejects NaN and infinities
+    // along with finite values outside the target's range.
+    (@check $x:ident: $S:ident(float) => $T:ident(float)) => {{
+        if size_of::<$S>() <= size_of::<$T>() {
+            Ok($x as $T)
+        } else if $x.is_nan() {
+            Err(CastErrorKind::NaN)
+        } else if $x.is_infinite() {
+            Err(CastErrorKind::Infinite)
+        } else {
+            let min_value = <$T as Bounded>::min_value();
+            let max_value = <$T as Bounded>::max_value();
+            if $x < min_value as $S {
+                Err(CastErrorKind::Underflow)
+            } else if $x > max_value as $S {
+                Err(CastErrorKind::Overflow)
+            } else {
+                Ok($x as $T)
+            }
+        }
+    }};
+}
+
+impl_cast_matrix!(i8(signed) i16(signed) i32(signed) i64(signed) i128(signed) isize(signed)
+                  u8(unsigned) u16(unsigned) u32(unsigned) u64(unsigned) u128(unsigned)
+                  usize(unsigned) f32(float) f64(float));
+
+/// Implements `CastFrom` between a newtype over a primitive number and every primitive
+/// number, so that the newtype joins the cast matrix of this crate.
+///
+/// Casts are checked against the wrapped type, e.g. `Meters(f64)` accepts exactly
+/// the values that `f64` does.
+///
+/// ```
+/// #[macro_use]
+/// extern crate num_traits;
+///
+/// use num_traits::CastFrom;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Level(u8);
+///
+/// impl_cast_for_newtype!(Level(u8));
+///
+/// fn main() {
+///     assert_eq!(Level::cast_from(200i32), Some(Level(200)));
+///     assert_eq!(Level::cast_from(-1i32), None);
+///     assert_eq!(f32::cast_from(Level(7)), Some(7.0));
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_cast_for_newtype {
+    ($N:ident($T:ty)) => {
+        $crate::impl_cast_for_newtype!(@each $N($T);
+                                       i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+        impl $crate::CastFrom<$N> for $N {
+            fn try_cast_from(x: $N) -> ::std::result::Result<$N, $crate::CastError> {
+                ::std::result::Result::Ok(x)
+            }
+        }
+    };
+    (@each $N:ident($T:ty); $($P:ident)*) => {
+        $(
+            impl $crate::CastFrom<$P> for $N {
+                fn try_cast_from(x: $P) -> ::std::result::Result<$N, $crate::CastError> {
+                    <$T as $crate::CastFrom<$P>>::try_cast_from(x).map($N)
+                }
+
+                fn cast_from(x: $P) -> ::std::option::Option<$N> {
+                    <$T as $crate::CastFrom<$P>>::cast_from(x).map($N)
+                }
+            }
+
+            impl $crate::CastFrom<$N> for $P {
+                fn try_cast_from(x: $N) -> ::std::result::Result<$P, $crate::CastError> {
+                    <$P as $crate::CastFrom<$T>>::try_cast_from(x.0)
+                }
+
+                fn cast_from(x: $N) -> ::std::option::Option<$P> {
+                    <$P as $crate::CastFrom<$T>>::cast_from(x.0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_int_char_bool {
+    ($($S:ident)*) => {
+        $(
+            impl CastCheck<$S> for char {
+                fn check(x: $S) -> Result<char, CastErrorKind> {
+                    let n = match <u32 as CastCheck<$S>>::check(x) {
+                        Ok(n) => n,
+                        Err(CastErrorKind::Underflow) => return Err(CastErrorKind::Underflow),
+                        Err(_) => return Err(CastErrorKind::Overflow),
+                    };
+                    match ::std::char::from_u32(n) {
+                        Some(c) => Ok(c),
+                        None if n > 0x10FFFF => Err(CastErrorKind::Overflow),
+                        // Surrogate code points are in range but are not valid chars.
+                        None => Err(CastErrorKind::Invalid),
+                    }
+                }
+            }
+
+            impl CastCheck<char> for $S {
+                fn check(x: char) -> Result<$S, CastErrorKind> {
+                    <$S as CastCheck<u32>>::check(x as u32).map_err(|_| CastErrorKind::Overflow)
+                }
+            }
+
+            impl CastCheck<$S> for bool {
+                fn check(x: $S) -> Result<bool, CastErrorKind> {
+                    if x == 0 {
+                        Ok(false)
+                    } else if x == 1 {
+                        Ok(true)
+                    } else if x < <$S>::zero() {
+                        Err(CastErrorKind::Underflow)
                     } else {
-                        None
+                        Err(CastErrorKind::Overflow)
                     }
                 }
             }
-        }
+
+            impl_cast_from_check!($S => char);
+            impl_cast_from_check!(char => $S);
+            impl_cast_from_check!($S => bool);
+
+            impl CastFrom<bool> for $S {
+                fn try_cast_from(x: bool) -> Result<$S, CastError> {
+                    Ok(x as $S)
+                }
+            }
+        )*
     }
 }
 
-macro_rules! impl_cast_int_to_uint {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                let zero = <$S>::zero();
-                let max_value = <$T>::max_value();
-                if zero <= x && x as u64 <= max_value as u64 {
-                    Some(x as $T)
-                } else {
-                    None
+impl_cast_same_type!(bool);
+impl_cast_same_type!(char);
+impl_cast_int_char_bool!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl<S, T> CastFrom<Wrapping<S>> for Wrapping<T>
+    where T: CastFrom<S>
+{
+    fn try_cast_from(x: Wrapping<S>) -> Result<Wrapping<T>, CastError> {
+        <T>::try_cast_from(x.0).map(Wrapping)
+    }
+
+    fn cast_from(x: Wrapping<S>) -> Option<Wrapping<T>> {
+        <T>::cast_from(x.0).map(Wrapping)
+    }
+}
+
+/// Converts `Self` into the other type with the semantics of the `as` operator.
+///
+/// Unlike `CastInto`, the conversion never fails and may lose information:
+///
+/// * Casting between two integers of the same size is a no-op, reinterpreting the bits.
+/// * Casting from a larger integer to a smaller integer truncates.
+/// * Casting from a smaller integer to a larger integer zero-extends if the source is
+///   unsigned and sign-extends if the source is signed.
+/// * Casting from a float to an integer rounds toward zero, saturates at the bounds
+///   of the integer type, and maps `NaN` to `0`.
+/// * Casting from an integer to a float produces the closest representable value,
+///   which may be infinite for `u128` to `f32`.
+/// * Casting from `f32` to `f64` is exact, while casting from `f64` to `f32` produces
+///   the closest representable value, which may be infinite.
+/// * Casting from `bool` to an integer yields `0` for `false` and `1` for `true`.
+/// * Casting from `char` to an integer yields its code point, truncated to the integer type.
+/// * Casting from `u8` to `char` yields the code point in the range `U+0000` to `U+00FF`.
+pub trait AsPrimitive<T>: 'static + Copy
+    where T: 'static + Copy
+{
+    /// Converts `self` into the type `T` using the `as` operator.
+    fn as_(self) -> T;
+}
+
+macro_rules! impl_as_primitive {
+    ($S:ty => $($T:ty)*) => {
+        $(
+            impl AsPrimitive<$T> for $S {
+                #[inline(always)]
+                fn as_(self) -> $T {
+                    self as $T
                 }
             }
-        }
+        )*
     }
 }
 
-macro_rules! impl_cast_int_to_float {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                Some(x as $T)
-            }
-        }
+impl_as_primitive!(i8 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(i16 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(i32 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(i64 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(i128 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(isize => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u8 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u16 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u32 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u64 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u128 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(usize => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(f32 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(f64 => i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_as_primitive!(u8 => char);
+impl_as_primitive!(bool => bool i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_as_primitive!(char => char i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Constructs `Self` from the other type, clamping out-of-range values
+/// to the bounds of `Self` instead of failing.
+pub trait SaturatingCastFrom<T>: Sized + Zero {
+    /// Constructs `Self` from the type `T`, saturating at the numeric bounds of `Self`.
+    ///
+    /// `NaN` maps to zero, and infinities map to the minimum or the maximum value of `Self`.
+    fn saturating_cast_from(x: T) -> Self {
+        Self::saturating_cast_from_or(x, Self::zero())
     }
+
+    /// Constructs `Self` from the type `T`, saturating at the numeric bounds of `Self`.
+    ///
+    /// `NaN` maps to `nan`, and infinities map to the minimum or the maximum value of `Self`.
+    fn saturating_cast_from_or(x: T, nan: Self) -> Self;
 }
 
-macro_rules! impl_cast_uint_to_int {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                let max_value = <$T>::max_value();
-                if x as u64 <= max_value as u64 {
-                    Some(x as $T)
-                } else {
-                    None
+/// Converts `Self` into the other type, clamping out-of-range values
+/// to the bounds of the other type instead of failing.
+pub trait SaturatingCastInto<T> {
+    /// Casts `Self` into the type `T`, saturating at the numeric bounds of `T`.
+    ///
+    /// `NaN` maps to zero, and infinities map to the minimum or the maximum value of `T`.
+    fn saturating_cast_into(self) -> T;
+}
+
+impl<S, T> SaturatingCastInto<T> for S
+    where T: SaturatingCastFrom<S>
+{
+    fn saturating_cast_into(self) -> T {
+        <T>::saturating_cast_from(self)
+    }
+}
+
+macro_rules! impl_saturating_cast {
+    ($kind:ident: $($S:ident)* => $targets:tt) => {
+        $(
+            impl_saturating_cast!(@row $kind, $S => $targets);
+        )*
+    };
+    (@row int_to_int, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl SaturatingCastFrom<$S> for $T {
+                fn saturating_cast_from_or(x: $S, _nan: $T) -> $T {
+                    let min_value = <$T as Bounded>::min_value();
+                    let max_value = <$T as Bounded>::max_value();
+                    if x < <$S>::zero() {
+                        if (x as i128) < min_value as i128 { min_value } else { x as $T }
+                    } else if x as u128 > max_value as u128 {
+                        max_value
+                    } else {
+                        x as $T
+                    }
                 }
             }
+        )*
+    };
+    (@row int_to_float, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl SaturatingCastFrom<$S> for $T {
+                fn saturating_cast_from_or(x: $S, _nan: $T) -> $T {
+                    let y = x as $T;
+                    if y.is_infinite() { <$T as Bounded>::max_value() } else { y }
+                }
+            }
+        )*
+    };
+    (@row float_to_int, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl SaturatingCastFrom<$S> for $T {
+                fn saturating_cast_from_or(x: $S, nan: $T) -> $T {
+                    // `as` already rounds toward zero and saturates at the bounds.
+                    if x.is_nan() { nan } else { x as $T }
+                }
+            }
+        )*
+    };
+    (@row float_to_float, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl SaturatingCastFrom<$S> for $T {
+                fn saturating_cast_from_or(x: $S, nan: $T) -> $T {
+                    let y = x as $T;
+                    if x.is_nan() {
+                        nan
+                    } else if y == <$T>::INFINITY {
+                        <$T as Bounded>::max_value()
+                    } else if y == <$T>::NEG_INFINITY {
+                        <$T as Bounded>::min_value()
+                    } else {
+                        y
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_cast!(int_to_int: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize));
+impl_saturating_cast!(int_to_float: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize => (f32 f64));
+impl_saturating_cast!(float_to_int: f32 f64 => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize));
+impl_saturating_cast!(float_to_float: f32 f64 => (f32 f64));
+
+/// Rounding modes for casting floats to integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with half-way cases rounded to the even integer.
+    NearestTiesEven,
+    /// Round to the nearest integer, with half-way cases rounded away from zero.
+    NearestTiesAway,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, as `CastFrom` does.
+    Truncate,
+}
 
+impl RoundingMode {
+    /// Rounds `x` to an integral value according to this mode.
+    pub fn round<F: Float>(self, x: F) -> F {
+        match self {
+            RoundingMode::NearestTiesEven => x.round_ties_even(),
+            RoundingMode::NearestTiesAway => x.round(),
+            RoundingMode::Floor => x.floor(),
+            RoundingMode::Ceil => x.ceil(),
+            RoundingMode::Truncate => x.trunc(),
         }
     }
 }
 
-macro_rules! impl_cast_uint_to_uint {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                if size_of::<$S>() <= size_of::<$T>() {
-                    Some(x as $T)
-                } else {
-                    let zero = <$S>::zero();
-                    let max_value = <$T>::max_value();
-                    if zero <= x && x as u64 <= max_value as u64 {
-                        Some(x as $T)
+/// Constructs `Self` from a float, rounding it according to a `RoundingMode`.
+pub trait RoundingCastFrom<F>: Sized {
+    /// Rounds `x` according to `mode` and constructs `Self` from the result,
+    /// returning the reason of the failure as an error.
+    fn try_cast_round(x: F, mode: RoundingMode) -> Result<Self, CastError>;
+
+    /// Rounds `x` according to `mode` and constructs `Self` from the result.
+    fn cast_round(x: F, mode: RoundingMode) -> Option<Self> {
+        Self::try_cast_round(x, mode).ok()
+    }
+}
+
+impl<F, T> RoundingCastFrom<F> for T
+    where F: Float, T: CastFrom<F>
+{
+    fn try_cast_round(x: F, mode: RoundingMode) -> Result<T, CastError> {
+        <T>::try_cast_from(mode.round(x))
+    }
+
+    fn cast_round(x: F, mode: RoundingMode) -> Option<T> {
+        <T>::cast_from(mode.round(x))
+    }
+}
+
+/// Constructs `Self` from the other type, rejecting conversions which lose precision.
+///
+/// Unlike `CastFrom`, integers which are too wide for the mantissa of the target float,
+/// floats with a fractional part and floats which are not representable by a narrower
+/// float type are reported as `CastError::Inexact`.
+pub trait ExactCastFrom<T>: Sized {
+    /// Constructs `Self` from the type `T` exactly, returning the reason of the failure as an error.
+    fn try_exact_cast_from(x: T) -> Result<Self, CastError>;
+
+    /// Constructs `Self` from the type `T` exactly.
+    fn exact_cast_from(x: T) -> Option<Self> {
+        Self::try_exact_cast_from(x).ok()
+    }
+}
+
+/// Converts `Self` into the other type, rejecting conversions which lose precision.
+pub trait ExactCastInto<T> {
+    /// Casts `Self` into the type `T` exactly, returning the reason of the failure as an error.
+    fn try_exact_cast_into(self) -> Result<T, CastError>;
+
+    /// Casts `Self` into the type `T` exactly.
+    fn exact_cast_into(self) -> Option<T>;
+}
+
+impl<S, T> ExactCastInto<T> for S
+    where T: ExactCastFrom<S>
+{
+    fn try_exact_cast_into(self) -> Result<T, CastError> {
+        <T>::try_exact_cast_from(self)
+    }
+
+    fn exact_cast_into(self) -> Option<T> {
+        <T>::exact_cast_from(self)
+    }
+}
+
+macro_rules! impl_exact_cast {
+    ($kind:ident: $($S:ident)* => $targets:tt) => {
+        $(
+            impl_exact_cast!(@row $kind, $S => $targets);
+        )*
+    };
+    (@impl $S:ident => $T:ident) => {
+        impl ExactCastFrom<$S> for $T {
+            #[inline]
+            fn try_exact_cast_from(x: $S) -> Result<$T, CastError> {
+                <$T as ExactCastCheck<$S>>::check_exact(x)
+                    .map_err(|kind| kind.describe(x, stringify!($S)))
+            }
+
+            #[inline]
+            fn exact_cast_from(x: $S) -> Option<$T> {
+                <$T as ExactCastCheck<$S>>::check_exact(x).ok()
+            }
+        }
+    };
+    (@row int_to_int, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl ExactCastCheck<$S> for $T {
+                fn check_exact(x: $S) -> Result<$T, CastErrorKind> {
+                    <$T as CastCheck<$S>>::check(x)
+                }
+            }
+
+            impl_exact_cast!(@impl $S => $T);
+        )*
+    };
+    (@row int_to_float, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl ExactCastCheck<$S> for $T {
+                fn check_exact(x: $S) -> Result<$T, CastErrorKind> {
+                    // The number of bits between the highest and the lowest set bits
+                    // must fit into the mantissa.
+                    let m = Int::unsigned_abs(x);
+                    let digits = if m == 0 {
+                        0
                     } else {
-                        None
+                        (size_of::<$S>() * 8) as u32 - m.leading_zeros() - m.trailing_zeros()
+                    };
+                    let y = x as $T;
+                    if digits <= <$T>::MANTISSA_DIGITS {
+                        Ok(y)
+                    } else if y.is_infinite() {
+                        Err(CastErrorKind::Overflow)
+                    } else {
+                        Err(CastErrorKind::Inexact)
                     }
                 }
             }
-        }
+
+            impl_exact_cast!(@impl $S => $T);
+        )*
+    };
+    (@row float_to_int, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl ExactCastCheck<$S> for $T {
+                fn check_exact(x: $S) -> Result<$T, CastErrorKind> {
+                    let y = <$T as CastCheck<$S>>::check(x)?;
+                    if x == x.trunc() {
+                        Ok(y)
+                    } else {
+                        Err(CastErrorKind::Inexact)
+                    }
+                }
+            }
+
+            impl_exact_cast!(@impl $S => $T);
+        )*
+    };
+    (@row float_to_float, $S:ident => ($($T:ident)*)) => {
+        $(
+            impl ExactCastCheck<$S> for $T {
+                fn check_exact(x: $S) -> Result<$T, CastErrorKind> {
+                    let y = x as $T;
+                    if y as $S == x || x.is_nan() {
+                        Ok(y)
+                    } else if y == <$T>::INFINITY {
+                        Err(CastErrorKind::Overflow)
+                    } else if y == <$T>::NEG_INFINITY {
+                        Err(CastErrorKind::Underflow)
+                    } else {
+                        Err(CastErrorKind::Inexact)
+                    }
+                }
+            }
+
+            impl_exact_cast!(@impl $S => $T);
+        )*
+    };
+}
+
+impl_exact_cast!(int_to_int: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize));
+impl_exact_cast!(int_to_float: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize => (f32 f64));
+impl_exact_cast!(float_to_int: f32 f64 => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize));
+impl_exact_cast!(float_to_float: f32 f64 => (f32 f64));
+
+/// Constructs `Self` from the other type via a conversion which can never fail
+/// nor lose information, such as `u8` to `i16` or `i32` to `f64`.
+///
+/// It is implemented exactly for the pairs of the cast matrix which are infallible on every
+/// platform, so `isize` and `usize` only accept types which fit into 16 bits.
+pub trait LosslessFrom<T>: CastFrom<T> {
+    /// Constructs `Self` from the type `T` without loss.
+    fn lossless_from(x: T) -> Self;
+}
+
+/// Converts `Self` into the other type via a conversion which can never fail
+/// nor lose information.
+pub trait LosslessInto<T> {
+    /// Converts `Self` into the type `T` without loss.
+    fn lossless_into(self) -> T;
+}
+
+impl<S, T> LosslessInto<T> for S
+    where T: LosslessFrom<S>
+{
+    fn lossless_into(self) -> T {
+        <T>::lossless_from(self)
     }
 }
 
-macro_rules! impl_cast_float_to_int {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                let min_value = <$T>::min_value();
-                let max_value = <$T>::max_value();
-                if min_value as $S <= x && x <= max_value as $S {
-                    Some(x as $T)
-                } else {
-                    None
+macro_rules! impl_lossless {
+    ($S:ty => $($T:ty)*) => {
+        $(
+            impl LosslessFrom<$S> for $T {
+                #[inline(always)]
+                fn lossless_from(x: $S) -> $T {
+                    x as $T
                 }
             }
+        )*
+    }
+}
+
+impl_lossless!(i8 => i8 i16 i32 i64 i128 isize f32 f64);
+impl_lossless!(i16 => i16 i32 i64 i128 isize f32 f64);
+impl_lossless!(i32 => i32 i64 i128 f64);
+impl_lossless!(i64 => i64 i128);
+impl_lossless!(i128 => i128);
+impl_lossless!(isize => isize);
+impl_lossless!(u8 => i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64 char);
+impl_lossless!(u16 => i32 i64 i128 u16 u32 u64 u128 usize f32 f64);
+impl_lossless!(u32 => i64 i128 u32 u64 u128 f64);
+impl_lossless!(u64 => i128 u64 u128);
+impl_lossless!(u128 => u128);
+impl_lossless!(usize => usize);
+impl_lossless!(f32 => f32 f64);
+impl_lossless!(f64 => f64);
+impl_lossless!(bool => bool i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_lossless!(char => char i32 i64 i128 u32 u64 u128);
+
+/// Converts a number into primitive numbers.
+///
+/// Only `to_i64` and `to_u64` are required. The other methods route through them by default,
+/// and `to_f64` uses whichever of the two succeeds. The methods take `&self` and no type
+/// parameters, so the trait can be used as a trait object.
+pub trait ToPrimitive {
+    /// Converts the value of `self` to an `i64`.
+    fn to_i64(&self) -> Option<i64>;
+
+    /// Converts the value of `self` to a `u64`.
+    fn to_u64(&self) -> Option<u64>;
+
+    /// Converts the value of `self` to an `i8`.
+    fn to_i8(&self) -> Option<i8> {
+        self.to_i64().and_then(i8::cast_from)
+    }
+
+    /// Converts the value of `self` to an `i16`.
+    fn to_i16(&self) -> Option<i16> {
+        self.to_i64().and_then(i16::cast_from)
+    }
+
+    /// Converts the value of `self` to an `i32`.
+    fn to_i32(&self) -> Option<i32> {
+        self.to_i64().and_then(i32::cast_from)
+    }
+
+    /// Converts the value of `self` to an `i128`.
+    fn to_i128(&self) -> Option<i128> {
+        self.to_i64().map(|n| n as i128).or_else(|| self.to_u64().map(|n| n as i128))
+    }
+
+    /// Converts the value of `self` to an `isize`.
+    fn to_isize(&self) -> Option<isize> {
+        self.to_i64().and_then(isize::cast_from)
+    }
+
+    /// Converts the value of `self` to a `u8`.
+    fn to_u8(&self) -> Option<u8> {
+        self.to_u64().and_then(u8::cast_from)
+    }
+
+    /// Converts the value of `self` to a `u16`.
+    fn to_u16(&self) -> Option<u16> {
+        self.to_u64().and_then(u16::cast_from)
+    }
+
+    /// Converts the value of `self` to a `u32`.
+    fn to_u32(&self) -> Option<u32> {
+        self.to_u64().and_then(u32::cast_from)
+    }
+
+    /// Converts the value of `self` to a `u128`.
+    fn to_u128(&self) -> Option<u128> {
+        self.to_u64().map(|n| n as u128)
+    }
+
+    /// Converts the value of `self` to a `usize`.
+    fn to_usize(&self) -> Option<usize> {
+        self.to_u64().and_then(usize::cast_from)
+    }
+
+    /// Converts the value of `self` to an `f32`.
+    fn to_f32(&self) -> Option<f32> {
+        self.to_f64().and_then(f32::cast_from)
+    }
+
+    /// Converts the value of `self` to an `f64`.
+    fn to_f64(&self) -> Option<f64> {
+        match self.to_i64() {
+            Some(n) => f64::cast_from(n),
+            None => self.to_u64().and_then(f64::cast_from),
         }
     }
 }
 
-macro_rules! impl_cast_float_to_uint {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                let zero = <$S>::zero();
-                let max_value = <$T>::max_value();
-                if zero <= x && x <= max_value as $S {
-                    Some(x as $T)
-                } else {
-                    None
-                }
-            }
+/// Constructs a number from primitive numbers.
+///
+/// Only `from_i64` and `from_u64` are required. The other methods route through them
+/// by default, and `from_f64` truncates toward zero like `CastFrom`.
+pub trait FromPrimitive: Sized {
+    /// Converts an `i64` to a value of this type.
+    fn from_i64(n: i64) -> Option<Self>;
+
+    /// Converts a `u64` to a value of this type.
+    fn from_u64(n: u64) -> Option<Self>;
+
+    /// Converts an `i8` to a value of this type.
+    fn from_i8(n: i8) -> Option<Self> {
+        Self::from_i64(n as i64)
+    }
+
+    /// Converts an `i16` to a value of this type.
+    fn from_i16(n: i16) -> Option<Self> {
+        Self::from_i64(n as i64)
+    }
+
+    /// Converts an `i32` to a value of this type.
+    fn from_i32(n: i32) -> Option<Self> {
+        Self::from_i64(n as i64)
+    }
+
+    /// Converts an `i128` to a value of this type.
+    fn from_i128(n: i128) -> Option<Self> {
+        match i64::cast_from(n) {
+            Some(n) => Self::from_i64(n),
+            None => u64::cast_from(n).and_then(Self::from_u64),
+        }
+    }
+
+    /// Converts an `isize` to a value of this type.
+    fn from_isize(n: isize) -> Option<Self> {
+        i64::cast_from(n).and_then(Self::from_i64)
+    }
+
+    /// Converts a `u8` to a value of this type.
+    fn from_u8(n: u8) -> Option<Self> {
+        Self::from_u64(n as u64)
+    }
+
+    /// Converts a `u16` to a value of this type.
+    fn from_u16(n: u16) -> Option<Self> {
+        Self::from_u64(n as u64)
+    }
+
+    /// Converts a `u32` to a value of this type.
+    fn from_u32(n: u32) -> Option<Self> {
+        Self::from_u64(n as u64)
+    }
+
+    /// Converts a `u128` to a value of this type.
+    fn from_u128(n: u128) -> Option<Self> {
+        u64::cast_from(n).and_then(Self::from_u64)
+    }
+
+    /// Converts a `usize` to a value of this type.
+    fn from_usize(n: usize) -> Option<Self> {
+        u64::cast_from(n).and_then(Self::from_u64)
+    }
+
+    /// Converts an `f32` to a value of this type.
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_f64(n as f64)
+    }
+
+    /// Converts an `f64` to a value of this type.
+    fn from_f64(n: f64) -> Option<Self> {
+        match i64::cast_from(n) {
+            Some(n) => Self::from_i64(n),
+            None => u64::cast_from(n).and_then(Self::from_u64),
         }
     }
 }
 
-macro_rules! impl_cast_float_to_float {
-    ($S:ty, $T:ty) => {
-        impl CastFrom<$S> for $T {
-            fn cast_from(x: $S) -> Option<$T> {
-                if size_of::<$S>() <= size_of::<$T>() {
-                    Some(x as $T)
-                } else {
-                    let y = x as f64;
-                    let max_value = <$S>::max_value();
-                    if -max_value as f64 <= y && y <= max_value as f64 {
-                        Some(x as $T)
-                    } else {
-                        None
-                    }
+/// Constructs a number from any type which implements `ToPrimitive`.
+///
+/// This bridges user-defined numbers into the cast matrix: a type which implements
+/// `ToPrimitive` can be cast to every primitive number through `NumCast::from`.
+pub trait NumCast: Sized + ToPrimitive {
+    /// Creates a number from another value which can be converted into a primitive number.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self>;
+}
+
+/// Casts from one number to another through `ToPrimitive` and `NumCast`.
+pub fn cast<T: ToPrimitive, U: NumCast>(n: T) -> Option<U> {
+    <U as NumCast>::from(n)
+}
+
+macro_rules! impl_primitive {
+    ($($t:ident($to:ident))*) => {
+        $(
+            impl ToPrimitive for $t {
+                fn to_i64(&self) -> Option<i64> { i64::cast_from(*self) }
+                fn to_u64(&self) -> Option<u64> { u64::cast_from(*self) }
+                fn to_i8(&self) -> Option<i8> { i8::cast_from(*self) }
+                fn to_i16(&self) -> Option<i16> { i16::cast_from(*self) }
+                fn to_i32(&self) -> Option<i32> { i32::cast_from(*self) }
+                fn to_i128(&self) -> Option<i128> { i128::cast_from(*self) }
+                fn to_isize(&self) -> Option<isize> { isize::cast_from(*self) }
+                fn to_u8(&self) -> Option<u8> { u8::cast_from(*self) }
+                fn to_u16(&self) -> Option<u16> { u16::cast_from(*self) }
+                fn to_u32(&self) -> Option<u32> { u32::cast_from(*self) }
+                fn to_u128(&self) -> Option<u128> { u128::cast_from(*self) }
+                fn to_usize(&self) -> Option<usize> { usize::cast_from(*self) }
+                fn to_f32(&self) -> Option<f32> { f32::cast_from(*self) }
+                fn to_f64(&self) -> Option<f64> { f64::cast_from(*self) }
+            }
+
+            impl FromPrimitive for $t {
+                fn from_i64(n: i64) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_u64(n: u64) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_i8(n: i8) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_i16(n: i16) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_i32(n: i32) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_i128(n: i128) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_isize(n: isize) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_u8(n: u8) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_u16(n: u16) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_u32(n: u32) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_u128(n: u128) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_usize(n: usize) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_f32(n: f32) -> Option<$t> { <$t>::cast_from(n) }
+                fn from_f64(n: f64) -> Option<$t> { <$t>::cast_from(n) }
+            }
+
+            impl NumCast for $t {
+                fn from<N: ToPrimitive>(n: N) -> Option<$t> {
+                    n.$to()
                 }
             }
-        }
+        )*
     }
 }
 
-impl_cast_same_type!(i8);
-impl_cast_int_to_int!(i8, i16);
-impl_cast_int_to_int!(i8, i32);
-impl_cast_int_to_int!(i8, i64);
-impl_cast_int_to_int!(i8, isize);
-impl_cast_int_to_uint!(i8, u8);
-impl_cast_int_to_uint!(i8, u16);
-impl_cast_int_to_uint!(i8, u32);
-impl_cast_int_to_uint!(i8, u64);
-impl_cast_int_to_uint!(i8, usize);
-impl_cast_int_to_float!(i8, f32);
-impl_cast_int_to_float!(i8, f64);
-
-impl_cast_int_to_int!(i16, i8);
-impl_cast_same_type!(i16);
-impl_cast_int_to_int!(i16, i32);
-impl_cast_int_to_int!(i16, i64);
-impl_cast_int_to_int!(i16, isize);
-impl_cast_int_to_uint!(i16, u8);
-impl_cast_int_to_uint!(i16, u16);
-impl_cast_int_to_uint!(i16, u32);
-impl_cast_int_to_uint!(i16, u64);
-impl_cast_int_to_uint!(i16, usize);
-impl_cast_int_to_float!(i16, f32);
-impl_cast_int_to_float!(i16, f64);
-
-impl_cast_int_to_int!(i32, i8);
-impl_cast_int_to_int!(i32, i16);
-impl_cast_same_type!(i32);
-impl_cast_int_to_int!(i32, i64);
-impl_cast_int_to_int!(i32, isize);
-impl_cast_int_to_uint!(i32, u8);
-impl_cast_int_to_uint!(i32, u16);
-impl_cast_int_to_uint!(i32, u32);
-impl_cast_int_to_uint!(i32, u64);
-impl_cast_int_to_uint!(i32, usize);
-impl_cast_int_to_float!(i32, f32);
-impl_cast_int_to_float!(i32, f64);
-
-impl_cast_int_to_int!(i64, i8);
-impl_cast_int_to_int!(i64, i16);
-impl_cast_int_to_int!(i64, i32);
-impl_cast_same_type!(i64);
-impl_cast_int_to_int!(i64, isize);
-impl_cast_int_to_uint!(i64, u8);
-impl_cast_int_to_uint!(i64, u16);
-impl_cast_int_to_uint!(i64, u32);
-impl_cast_int_to_uint!(i64, u64);
-impl_cast_int_to_uint!(i64, usize);
-impl_cast_int_to_float!(i64, f32);
-impl_cast_int_to_float!(i64, f64);
-
-impl_cast_int_to_int!(isize, i8);
-impl_cast_int_to_int!(isize, i16);
-impl_cast_int_to_int!(isize, i32);
-impl_cast_int_to_int!(isize, i64);
-impl_cast_same_type!(isize);
-impl_cast_int_to_uint!(isize, u8);
-impl_cast_int_to_uint!(isize, u16);
-impl_cast_int_to_uint!(isize, u32);
-impl_cast_int_to_uint!(isize, u64);
-impl_cast_int_to_uint!(isize, usize);
-impl_cast_int_to_float!(isize, f32);
-impl_cast_int_to_float!(isize, f64);
-
-impl_cast_uint_to_uint!(u8, i8);
-impl_cast_uint_to_int!(u8, i16);
-impl_cast_uint_to_int!(u8, i32);
-impl_cast_uint_to_int!(u8, i64);
-impl_cast_uint_to_int!(u8, isize);
-impl_cast_same_type!(u8);
-impl_cast_uint_to_uint!(u8, u16);
-impl_cast_uint_to_uint!(u8, u32);
-impl_cast_uint_to_uint!(u8, u64);
-impl_cast_uint_to_uint!(u8, usize);
-impl_cast_int_to_float!(u8, f32);
-impl_cast_int_to_float!(u8, f64);
-
-impl_cast_uint_to_uint!(u16, i8);
-impl_cast_uint_to_int!(u16, i16);
-impl_cast_uint_to_int!(u16, i32);
-impl_cast_uint_to_int!(u16, i64);
-impl_cast_uint_to_int!(u16, isize);
-impl_cast_uint_to_uint!(u16, u8);
-impl_cast_same_type!(u16);
-impl_cast_uint_to_uint!(u16, u32);
-impl_cast_uint_to_uint!(u16, u64);
-impl_cast_uint_to_uint!(u16, usize);
-impl_cast_int_to_float!(u16, f32);
-impl_cast_int_to_float!(u16, f64);
-
-impl_cast_uint_to_uint!(u32, i8);
-impl_cast_uint_to_int!(u32, i16);
-impl_cast_uint_to_int!(u32, i32);
-impl_cast_uint_to_int!(u32, i64);
-impl_cast_uint_to_int!(u32, isize);
-impl_cast_uint_to_uint!(u32, u8);
-impl_cast_uint_to_uint!(u32, u16);
-impl_cast_same_type!(u32);
-impl_cast_uint_to_uint!(u32, u64);
-impl_cast_uint_to_uint!(u32, usize);
-impl_cast_int_to_float!(u32, f32);
-impl_cast_int_to_float!(u32, f64);
-
-impl_cast_uint_to_uint!(u64, i8);
-impl_cast_uint_to_int!(u64, i16);
-impl_cast_uint_to_int!(u64, i32);
-impl_cast_uint_to_int!(u64, i64);
-impl_cast_uint_to_int!(u64, isize);
-impl_cast_uint_to_uint!(u64, u8);
-impl_cast_uint_to_uint!(u64, u16);
-impl_cast_uint_to_uint!(u64, u32);
-impl_cast_same_type!(u64);
-impl_cast_uint_to_uint!(u64, usize);
-impl_cast_int_to_float!(u64, f32);
-impl_cast_int_to_float!(u64, f64);
-
-impl_cast_uint_to_uint!(usize, i8);
-impl_cast_uint_to_int!(usize, i16);
-impl_cast_uint_to_int!(usize, i32);
-impl_cast_uint_to_int!(usize, i64);
-impl_cast_uint_to_int!(usize, isize);
-impl_cast_uint_to_uint!(usize, u8);
-impl_cast_uint_to_uint!(usize, u16);
-impl_cast_uint_to_uint!(usize, u32);
-impl_cast_uint_to_uint!(usize, u64);
-impl_cast_same_type!(usize);
-impl_cast_int_to_float!(usize, f32);
-impl_cast_int_to_float!(usize, f64);
-
-impl_cast_float_to_int!(f32, i8);
-impl_cast_float_to_int!(f32, i16);
-impl_cast_float_to_int!(f32, i32);
-impl_cast_float_to_int!(f32, i64);
-impl_cast_float_to_int!(f32, isize);
-impl_cast_float_to_uint!(f32, u8);
-impl_cast_float_to_uint!(f32, u16);
-impl_cast_float_to_uint!(f32, u32);
-impl_cast_float_to_uint!(f32, u64);
-impl_cast_float_to_uint!(f32, usize);
-impl_cast_same_type!(f32);
-impl_cast_float_to_float!(f32, f64);
-
-impl_cast_float_to_int!(f64, i8);
-impl_cast_float_to_int!(f64, i16);
-impl_cast_float_to_int!(f64, i32);
-impl_cast_float_to_int!(f64, i64);
-impl_cast_float_to_int!(f64, isize);
-impl_cast_float_to_uint!(f64, u8);
-impl_cast_float_to_uint!(f64, u16);
-impl_cast_float_to_uint!(f64, u32);
-impl_cast_float_to_uint!(f64, u64);
-impl_cast_float_to_uint!(f64, usize);
-impl_cast_float_to_float!(f64, f32);
-impl_cast_same_type!(f64);
+impl_primitive!(i8(to_i8) i16(to_i16) i32(to_i32) i64(to_i64) i128(to_i128) isize(to_isize)
+                u8(to_u8) u16(to_u16) u32(to_u32) u64(to_u64) u128(to_u128) usize(to_usize)
+                f32(to_f32) f64(to_f64));
 
 #[test]
 fn test_cast() {
@@ -394,3 +1299,363 @@ fn test_cast() {
     assert_eq!(b, 32.0f32);
     assert_eq!(c, None);
 }
+
+#[test]
+fn test_as_primitive() {
+    fn to_u8<T: AsPrimitive<u8>>(x: T) -> u8 {
+        x.as_()
+    }
+
+    assert_eq!(to_u8(300i32), 44);
+    assert_eq!(to_u8(-1i8), 255);
+    assert_eq!(to_u8(300.7f32), 255);
+    assert_eq!(to_u8(-3.5f64), 0);
+    assert_eq!(to_u8(f64::NAN), 0);
+    assert_eq!(to_u8(true), 1);
+    assert_eq!(to_u8('\u{1F600}'), 0);
+    assert_eq!(AsPrimitive::<char>::as_(0xe9u8), '\u{e9}');
+    assert_eq!(AsPrimitive::<i32>::as_(-2.9f64), -2);
+    assert_eq!(AsPrimitive::<f32>::as_(u128::MAX), f32::INFINITY);
+    assert_eq!(AsPrimitive::<f32>::as_(16777217i32), 16777216.0);
+    assert_eq!(AsPrimitive::<i64>::as_(u64::MAX), -1);
+}
+
+#[test]
+fn test_saturating_cast() {
+    assert_eq!(u8::saturating_cast_from(300i32), 255);
+    assert_eq!(u8::saturating_cast_from(-5i32), 0);
+    assert_eq!(i8::saturating_cast_from(-300i64), -128);
+    assert_eq!(i8::saturating_cast_from(u128::MAX), 127);
+    assert_eq!(u128::saturating_cast_from(i128::MIN), 0);
+    assert_eq!(i64::saturating_cast_from(1.0e30f64), i64::MAX);
+    assert_eq!(i64::saturating_cast_from(f32::NEG_INFINITY), i64::MIN);
+    assert_eq!(u16::saturating_cast_from(f64::NAN), 0);
+    assert_eq!(u16::saturating_cast_from_or(f64::NAN, u16::MAX), u16::MAX);
+    assert_eq!(i32::saturating_cast_from(-2.7f32), -2);
+    assert_eq!(f32::saturating_cast_from(u128::MAX), f32::MAX);
+    assert_eq!(f32::saturating_cast_from(-1.0e300f64), f32::MIN);
+    assert_eq!(f64::saturating_cast_from(f32::INFINITY), f64::MAX);
+    assert_eq!(f32::saturating_cast_from_or(f64::NAN, -1.0), -1.0);
+    assert_eq!(f64::saturating_cast_from(0.5f32), 0.5);
+
+    let y: u8 = 1000u16.saturating_cast_into();
+    assert_eq!(y, 255);
+}
+
+#[test]
+fn test_cast_round() {
+    use RoundingMode::*;
+
+    let cents = |price: f64, mode| i64::cast_round(price * 100.0, mode);
+    assert_eq!(cents(19.995, NearestTiesAway), Some(2000));
+    assert_eq!(cents(-0.125, NearestTiesEven), Some(-12));
+
+    assert_eq!(i32::cast_round(2.5f64, NearestTiesEven), Some(2));
+    assert_eq!(i32::cast_round(3.5f64, NearestTiesEven), Some(4));
+    assert_eq!(i32::cast_round(-2.5f32, NearestTiesEven), Some(-2));
+    assert_eq!(i32::cast_round(2.5f64, NearestTiesAway), Some(3));
+    assert_eq!(i32::cast_round(-2.5f32, NearestTiesAway), Some(-3));
+    assert_eq!(i32::cast_round(-2.1f64, Floor), Some(-3));
+    assert_eq!(i32::cast_round(-2.9f64, Ceil), Some(-2));
+    assert_eq!(i32::cast_round(-2.9f64, Truncate), Some(-2));
+    assert_eq!(u8::cast_round(255.4f32, NearestTiesEven), Some(255));
+    assert_eq!(u8::cast_round(255.5f32, NearestTiesEven), None);
+    assert_eq!(u8::cast_round(255.1f32, Ceil), None);
+    assert_eq!(u8::cast_round(-0.4f64, NearestTiesAway), Some(0));
+    assert_eq!(u8::cast_round(-0.4f64, Floor), None);
+    assert_eq!(u8::try_cast_round(f64::NAN, Floor), Err(CastError::NaN("NaN_f64".to_string())));
+}
+
+#[test]
+fn test_exact_cast() {
+    assert_eq!(f32::exact_cast_from(16777216i32), Some(16777216.0));
+    assert_eq!(f32::try_exact_cast_from(16777217i32),
+               Err(CastError::Inexact("16777217_i32".to_string())));
+    assert_eq!(f32::exact_cast_from(-16777216i64 * 1024), Some(-17179869184.0));
+    assert_eq!(f32::exact_cast_from(i128::MIN), Some(-1.7014118e38));
+    assert_eq!(f32::try_exact_cast_from(u64::MAX),
+               Err(CastError::Inexact("18446744073709551615_u64".to_string())));
+    assert_eq!(f32::try_exact_cast_from(u128::MAX),
+               Err(CastError::Overflow("340282366920938463463374607431768211455_u128".to_string())));
+    assert_eq!(f64::exact_cast_from(1u64 << 53), Some(9007199254740992.0));
+    assert_eq!(f64::exact_cast_from((1u64 << 53) + 1), None);
+    assert_eq!(f64::exact_cast_from(0u8), Some(0.0));
+
+    assert_eq!(f32::exact_cast_from(0.5f64), Some(0.5));
+    assert_eq!(f32::try_exact_cast_from(0.1f64), Err(CastError::Inexact("0.1_f64".to_string())));
+    assert_eq!(f32::try_exact_cast_from(1.0e300f64),
+               Err(CastError::Overflow("1e300_f64".to_string())));
+    assert_eq!(f32::exact_cast_from(f64::NEG_INFINITY), Some(f32::NEG_INFINITY));
+    assert!(f32::exact_cast_from(f64::NAN).unwrap().is_nan());
+    assert_eq!(f64::exact_cast_from(0.1f32), Some(0.1f32 as f64));
+
+    assert_eq!(i32::exact_cast_from(-42.0f64), Some(-42));
+    assert_eq!(i32::try_exact_cast_from(-42.5f64), Err(CastError::Inexact("-42.5_f64".to_string())));
+    assert_eq!(u8::exact_cast_from(256.0f32), None);
+    assert_eq!(u8::exact_cast_from(300u16), None);
+
+    let x: Option<f64> = 12345i32.exact_cast_into();
+    assert_eq!(x, Some(12345.0));
+}
+
+#[test]
+fn test_cast_float_to_int_bounds() {
+    macro_rules! test_float_to_signed {
+        ($S:ident => $($T:ident)*) => {
+            $({
+                let min = <$T>::MIN as $S;
+                let upper = -min;
+                let below_upper = upper.next_down();
+                let below_min = min.next_down();
+                let overflow = CastError::Overflow(describe(upper, stringify!($S)));
+
+                assert_eq!(<$T>::cast_from(min), Some(<$T>::MIN));
+                assert_eq!(<$T>::try_cast_from(upper), Err(overflow));
+                if upper - below_upper < 1.0 {
+                    assert_eq!(<$T>::cast_from(below_upper), Some(<$T>::MAX));
+                } else {
+                    assert_eq!(<$T>::cast_from(below_upper).unwrap() as $S, below_upper);
+                }
+                if (<$T>::MAX as $S) < upper {
+                    assert_eq!(<$T>::cast_from(<$T>::MAX as $S), Some(<$T>::MAX));
+                }
+                if min - below_min < 1.0 {
+                    let min_m1 = min - 1.0;
+                    let underflow = CastError::Underflow(describe(min_m1, stringify!($S)));
+                    assert_eq!(<$T>::cast_from(below_min), Some(<$T>::MIN));
+                    assert_eq!(<$T>::try_cast_from(min_m1), Err(underflow));
+                    assert_eq!(<$T>::cast_from(min_m1.next_up()), Some(<$T>::MIN));
+                } else {
+                    let underflow = CastError::Underflow(describe(below_min, stringify!($S)));
+                    assert_eq!(<$T>::try_cast_from(below_min), Err(underflow));
+                }
+            })*
+        }
+    }
+
+    macro_rules! test_float_to_unsigned {
+        ($S:ident => $($T:ident)*) => {
+            $({
+                let upper = (<$T>::MAX / 2 + 1) as $S * 2.0;
+                let underflow = CastError::Underflow(describe(-1.0 as $S, stringify!($S)));
+
+                assert_eq!(<$T>::try_cast_from(-1.0 as $S), Err(underflow));
+                assert_eq!(<$T>::cast_from((-1.0 as $S).next_up()), Some(0));
+                assert_eq!(<$T>::cast_from(-0.0 as $S), Some(0));
+                if upper.is_finite() {
+                    let below_upper = upper.next_down();
+                    let overflow = CastError::Overflow(describe(upper, stringify!($S)));
+                    assert_eq!(<$T>::try_cast_from(upper), Err(overflow));
+                    if upper - below_upper < 1.0 {
+                        assert_eq!(<$T>::cast_from(below_upper), Some(<$T>::MAX));
+                    } else {
+                        assert_eq!(<$T>::cast_from(below_upper).unwrap() as $S, below_upper);
+                    }
+                } else {
+                    assert_eq!(<$T>::cast_from(<$S>::MAX), Some(<$S>::MAX as $T));
+                }
+                if (<$T>::MAX as $S) < upper {
+                    assert_eq!(<$T>::cast_from(<$T>::MAX as $S), Some(<$T>::MAX));
+                }
+            })*
+        }
+    }
+
+    test_float_to_signed!(f32 => i8 i16 i32 i64 i128 isize);
+    test_float_to_signed!(f64 => i8 i16 i32 i64 i128 isize);
+    test_float_to_unsigned!(f32 => u8 u16 u32 u64 u128 usize);
+    test_float_to_unsigned!(f64 => u8 u16 u32 u64 u128 usize);
+
+    assert_eq!(i64::cast_from(2.0f64.powi(63)), None);
+    assert_eq!(i64::cast_from(2.0f64.powi(63).next_down()), Some(9223372036854774784));
+    assert_eq!(u64::cast_from(2.0f64.powi(64)), None);
+    assert_eq!(i32::cast_from(2147483647.9f64), Some(i32::MAX));
+    assert_eq!(i32::cast_from(-2147483648.9f64), Some(i32::MIN));
+    assert_eq!(i32::cast_from(2147483648.0f32), None);
+    assert_eq!(i8::cast_from(-128.5f32), Some(-128));
+    assert_eq!(u8::cast_from(255.9f64), Some(255));
+}
+
+#[test]
+fn test_cast_char_bool_wrapping() {
+    assert_eq!(char::cast_from(0x41u8), Some('A'));
+    assert_eq!(char::cast_from(0x1F600i64), Some('\u{1F600}'));
+    assert_eq!(char::cast_from(0x10FFFFu128), Some('\u{10FFFF}'));
+    assert_eq!(char::try_cast_from(0x110000u32), Err(CastError::Overflow("1114112_u32".to_string())));
+    assert_eq!(char::try_cast_from(u64::MAX),
+               Err(CastError::Overflow("18446744073709551615_u64".to_string())));
+    assert_eq!(char::try_cast_from(0xD800u16), Err(CastError::Invalid("55296_u16".to_string())));
+    assert_eq!(char::try_cast_from(0xDFFFi64).unwrap_err().to_string(),
+               "cannot cast 57343_i64: value is not valid for the target type");
+    assert_eq!(char::try_cast_from(-1i32), Err(CastError::Underflow("-1_i32".to_string())));
+    assert_eq!(u8::cast_from('A'), Some(65));
+    assert_eq!(u32::cast_from('\u{10FFFF}'), Some(0x10FFFF));
+    assert_eq!(i8::try_cast_from('\u{e9}'), Err(CastError::Overflow("'é'_char".to_string())));
+
+    assert_eq!(bool::cast_from(0i8), Some(false));
+    assert_eq!(bool::cast_from(1u64), Some(true));
+    assert_eq!(bool::try_cast_from(2u8), Err(CastError::Overflow("2_u8".to_string())));
+    assert_eq!(bool::try_cast_from(-1isize), Err(CastError::Underflow("-1_isize".to_string())));
+    assert_eq!(i128::cast_from(true), Some(1));
+
+    assert_eq!(Wrapping::<u8>::cast_from(Wrapping(255i32)), Some(Wrapping(255)));
+    assert_eq!(Wrapping::<u8>::cast_from(Wrapping(256i32)), None);
+    assert_eq!(Wrapping::<i16>::cast_from(Wrapping(-1.5f64)), Some(Wrapping(-1)));
+    assert_eq!(Wrapping::<char>::cast_from(Wrapping(0x61u8)), Some(Wrapping('a')));
+}
+
+#[test]
+fn test_cast_for_newtype() {
+    #[derive(Debug, PartialEq)]
+    struct Meters(f64);
+
+    #[derive(Debug, PartialEq)]
+    struct Count(u16);
+
+    impl_cast_for_newtype!(Meters(f64));
+    impl_cast_for_newtype!(Count(u16));
+
+    assert_eq!(Meters::cast_from(3u8), Some(Meters(3.0)));
+    assert_eq!(Meters::cast_from(Meters(1.5)), Some(Meters(1.5)));
+    assert_eq!(i32::cast_from(Meters(-2.5)), Some(-2));
+    assert_eq!(u8::try_cast_from(Meters(-2.5)), Err(CastError::Underflow("-2.5_f64".to_string())));
+    assert_eq!(Count::cast_from(70000i64), None);
+    assert_eq!(Count::cast_from(1.0e3f32), Some(Count(1000)));
+    assert_eq!(i8::cast_from(Count(300)), None);
+
+    let m: Option<Meters> = 7i32.cast_into();
+    assert_eq!(m, Some(Meters(7.0)));
+}
+
+#[test]
+fn test_to_from_primitive() {
+    // A user-defined number only needs a couple of methods to join the matrix.
+    #[derive(Debug, PartialEq)]
+    struct Small(i64);
+
+    impl ToPrimitive for Small {
+        fn to_i64(&self) -> Option<i64> { Some(self.0) }
+        fn to_u64(&self) -> Option<u64> { u64::cast_from(self.0) }
+    }
+
+    impl FromPrimitive for Small {
+        fn from_i64(n: i64) -> Option<Small> { Some(Small(n)) }
+        fn from_u64(n: u64) -> Option<Small> { i64::cast_from(n).map(Small) }
+    }
+
+    assert_eq!(cast::<_, u8>(Small(200)), Some(200));
+    assert_eq!(cast::<_, u8>(Small(-1)), None);
+    assert_eq!(cast::<_, f32>(Small(-3)), Some(-3.0));
+    assert_eq!(cast::<_, i128>(Small(i64::MIN)), Some(i64::MIN as i128));
+    assert_eq!(<i16 as NumCast>::from(Small(70000)), None);
+    assert_eq!(Small(5).to_u128(), Some(5));
+    assert_eq!(Small::from_f64(-2.7), Some(Small(-2)));
+    assert_eq!(Small::from_u128(u64::MAX as u128), None);
+    assert_eq!(Small::from_i8(-8), Some(Small(-8)));
+
+    struct Big(u64);
+
+    impl ToPrimitive for Big {
+        fn to_i64(&self) -> Option<i64> { i64::cast_from(self.0) }
+        fn to_u64(&self) -> Option<u64> { Some(self.0) }
+    }
+
+    assert_eq!(cast::<_, i128>(Big(1 << 63)), Some(1 << 63));
+    assert_eq!(Big(u64::MAX).to_i128(), Some(u64::MAX as i128));
+    assert_eq!(Big(u64::MAX).to_i64(), None);
+
+    let xs: Vec<Box<dyn ToPrimitive>> = vec![Box::new(Small(1)), Box::new(2.5f32), Box::new(300u16)];
+    let bytes: Vec<Option<u8>> = xs.iter().map(|x| x.to_u8()).collect();
+    assert_eq!(bytes, vec![Some(1), Some(2), None]);
+
+    assert_eq!(u32::from_f64(4.0e9), Some(4000000000));
+    assert_eq!(i8::from_u128(128), None);
+    assert_eq!(cast::<f64, i32>(1.0e10), None);
+    assert_eq!(u128::MAX.to_f32(), None);
+}
+
+#[test]
+fn test_lossless() {
+    fn widen<T: LosslessInto<f64>>(xs: Vec<T>) -> Vec<f64> {
+        xs.into_iter().map(LosslessInto::lossless_into).collect()
+    }
+
+    assert_eq!(widen(vec![i32::MIN, 0, i32::MAX]), vec![-2147483648.0, 0.0, 2147483647.0]);
+    assert_eq!(widen(vec![1.5f32]), vec![1.5]);
+    assert_eq!(i16::lossless_from(255u8), 255);
+    assert_eq!(i128::lossless_from(u64::MAX), u64::MAX as i128);
+    assert_eq!(f32::lossless_from(-32768i16), -32768.0);
+    assert_eq!(usize::lossless_from(65535u16), 65535);
+    assert_eq!(u8::lossless_from(true), 1);
+    assert_eq!(char::lossless_from(0x41u8), 'A');
+    assert_eq!(i32::lossless_from('\u{10FFFF}'), 0x10FFFF);
+
+    let wide: u128 = u64::MAX.lossless_into();
+    assert_eq!(wide, u64::MAX as u128);
+}
+
+#[test]
+fn test_try_cast() {
+    assert_eq!(u8::try_cast_from(300u16), Err(CastError::Overflow("300_u16".to_string())));
+    assert_eq!(u32::try_cast_from(-1i8), Err(CastError::Underflow("-1_i8".to_string())));
+    assert_eq!(i8::try_cast_from(-129i64), Err(CastError::Underflow("-129_i64".to_string())));
+    assert_eq!(i16::try_cast_from(f32::NAN), Err(CastError::NaN("NaN_f32".to_string())));
+    assert_eq!(u64::try_cast_from(f64::INFINITY), Err(CastError::Infinite("inf_f64".to_string())));
+    assert_eq!(f32::try_cast_from(-1.0e300f64), Err(CastError::Underflow("-1e300_f64".to_string())));
+    assert_eq!(f32::try_cast_from(f64::NEG_INFINITY), Err(CastError::Infinite("-inf_f64".to_string())));
+    assert_eq!(f32::try_cast_from(f64::NAN), Err(CastError::NaN("NaN_f64".to_string())));
+    assert!(f64::try_cast_from(f32::NAN).unwrap().is_nan());
+    assert_eq!(500i32.try_cast_into(), Ok(500u16));
+
+    // `cast_from` skips the description, but must agree with `try_cast_from`.
+    for &x in &[0.0f64, -0.5, 255.9, 256.0, -1.0, 1.0e10, f64::NAN, f64::INFINITY] {
+        assert_eq!(u8::cast_from(x), u8::try_cast_from(x).ok());
+        assert_eq!(u8::exact_cast_from(x), u8::try_exact_cast_from(x).ok());
+        assert_eq!(u8::cast_round(x, RoundingMode::Ceil), u8::try_cast_round(x, RoundingMode::Ceil).ok());
+    }
+
+    let err = i8::try_cast_from(128u8).unwrap_err();
+    assert_eq!(err.value(), "128_u8");
+    assert_eq!(err.to_string(), "cannot cast 128_u8: value is too large for the target type");
+}
+
+#[test]
+fn test_cast_128() {
+    assert_eq!(u8::cast_from(200u128), Some(200));
+    assert_eq!(i8::cast_from(200u8), None);
+    assert_eq!(i8::cast_from(-128i128), Some(-128));
+    assert_eq!(i64::cast_from(i128::MIN), None);
+    assert_eq!(i128::cast_from(u128::MAX), None);
+    assert_eq!(u128::cast_from(i128::MAX), Some(i128::MAX as u128));
+    assert_eq!(u128::cast_from(-1i128), None);
+    assert_eq!(u64::cast_from(u128::MAX), None);
+    assert_eq!(u64::cast_from(u64::MAX as u128), Some(u64::MAX));
+    assert_eq!(i128::cast_from(u64::MAX), Some(u64::MAX as i128));
+    assert_eq!(f64::cast_from(u128::MAX), Some(u128::MAX as f64));
+    assert_eq!(f32::cast_from(u128::MAX), None);
+    assert_eq!(f32::cast_from(u128::MAX - (1 << 104)), Some(f32::MAX));
+    assert!(matches!(f32::try_cast_from(u128::MAX), Err(CastError::Overflow(_))));
+    assert_eq!(i128::cast_from(-1.0e30f64), Some(-1_000_000_000_000_000_019_884_624_838_656));
+}
+
+#[test]
+fn test_bounded() {
+    assert_eq!(<i8 as Bounded>::min_value(), -128);
+    assert_eq!(<u16 as Bounded>::max_value(), 65535);
+    assert_eq!(<f32 as Bounded>::min_value(), f32::MIN);
+    assert_eq!(<Wrapping<u8> as Bounded>::max_value(), Wrapping(255));
+    assert_eq!(<(u8, i16, f64) as Bounded>::min_value(), (0, -32768, f64::MIN));
+}
+
+#[test]
+fn test_from_str_radix() {
+    assert_eq!(<i32 as Num>::from_str_radix("-ff", 16), Ok(-255));
+    assert_eq!(<u8 as Num>::from_str_radix("256", 10), Err(ParseNumError::Overflow));
+    assert_eq!(<i8 as Num>::from_str_radix("-129", 10), Err(ParseNumError::Underflow));
+    assert_eq!(<f64 as Num>::from_str_radix("1e3", 10), Ok(1000.0));
+    assert_eq!(<f64 as Num>::from_str_radix("-10.1", 2), Ok(-2.5));
+    assert_eq!(<f32 as Num>::from_str_radix("ff.8", 16), Ok(255.5));
+    assert_eq!(<f64 as Num>::from_str_radix("", 16), Err(ParseNumError::Empty));
+    assert_eq!(<f64 as Num>::from_str_radix("1.2", 2), Err(ParseNumError::InvalidDigit));
+    assert_eq!(<f64 as Num>::from_str_radix("-", 8), Err(ParseNumError::InvalidDigit));
+}
@@ -0,0 +1,213 @@
+use ::{Signed, UnsignedInt, Integer, WideningOps};
+
+/// Modular arithmetic on unsigned integers that never overflows,
+/// for any modulus up to `Self::max_value()`.
+///
+/// Operands need not be reduced; every result lies in `0..m`.
+/// All methods panic if the modulus `m` is zero.
+pub trait ModularOps: UnsignedInt {
+    /// Computes `(self * rhs) % m`.
+    fn mod_mul(self, rhs: Self, m: Self) -> Self;
+
+    /// Computes `(self + rhs) % m`.
+    fn mod_add(self, rhs: Self, m: Self) -> Self {
+        let (a, b) = (self % m, rhs % m);
+        // `a + b` may overflow, but `m - b` cannot.
+        if a >= m - b { a - (m - b) } else { a + b }
+    }
+
+    /// Computes `(self - rhs) mod m`, the least non-negative residue of the difference.
+    fn mod_sub(self, rhs: Self, m: Self) -> Self {
+        let (a, b) = (self % m, rhs % m);
+        if a >= b { a - b } else { m - (b - a) }
+    }
+
+    /// Computes `self.pow(exp) % m`, using exponentiation by squaring.
+    fn mod_pow(self, exp: Self, m: Self) -> Self {
+        let mut result = Self::one() % m;
+        let mut base = self % m;
+        let mut exp = exp;
+        while exp != Self::zero() {
+            if exp & Self::one() == Self::one() {
+                result = result.mod_mul(base, m);
+            }
+            base = base.mod_mul(base, m);
+            exp = exp >> 1;
+        }
+        result
+    }
+
+    /// Computes the multiplicative inverse of `self` modulo `m`, that is the `x` in `0..m`
+    /// such that `(self * x) % m == 1 % m`. Returns `None` if `self` and `m` are not coprime.
+    fn mod_inv(self, m: Self) -> Option<Self> {
+        // The extended Euclidean algorithm, keeping the coefficient of `self`
+        // reduced modulo `m` so that it stays unsigned.
+        let (mut r0, mut r1) = (m, self % m);
+        let (mut t0, mut t1) = (Self::zero(), Self::one() % m);
+        while r1 != Self::zero() {
+            let q = r0 / r1;
+            let r = r0 - q * r1;
+            r0 = r1;
+            r1 = r;
+            let t = t0.mod_sub(q.mod_mul(t1, m), m);
+            t0 = t1;
+            t1 = t;
+        }
+        if r0 == Self::one() { Some(t0) } else { None }
+    }
+}
+
+macro_rules! impl_modular_widening {
+    ($($t:ty)*) => {
+        $(
+            impl ModularOps for $t {
+                fn mod_mul(self, rhs: Self, m: Self) -> Self {
+                    // The remainder is less than `m`, so its high half is always zero.
+                    let wide = self.widen() * rhs.widen() % m.widen();
+                    <$t as WideningOps>::split_wide(wide).0
+                }
+            }
+        )*
+    }
+}
+
+impl_modular_widening!(u8 u16 u32 u64 usize);
+
+impl ModularOps for u128 {
+    fn mod_mul(self, rhs: Self, m: Self) -> Self {
+        // There is no wider type, so fall back to double-and-add.
+        let mut result = 0;
+        let mut a = self % m;
+        let mut b = rhs % m;
+        while b != 0 {
+            if b & 1 == 1 {
+                result = result.mod_add(a, m);
+            }
+            a = a.mod_add(a, m);
+            b >>= 1;
+        }
+        result
+    }
+}
+
+/// The extended Euclidean algorithm on signed integers.
+pub trait ExtendedGcd: Integer + Signed {
+    /// Returns `(g, x, y)` such that `g == self.gcd(other)` and `self * x + other * y == g`.
+    ///
+    /// The coefficients are the minimal ones found by the algorithm, with
+    /// `|x| <= max(1, |other / g|)` and `|y| <= max(1, |self / g|)`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows, i.e. if `g` is `2^(n - 1)`
+    /// for an integer of `n` bits, as for `extended_gcd(::MIN, 0)`.
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+        self.checked_extended_gcd(other).expect("attempt to compute gcd with overflow")
+    }
+
+    /// Checked extended Euclidean algorithm. Computes `self.extended_gcd(other)`,
+    /// returning `None` if the result overflows.
+    fn checked_extended_gcd(self, other: Self) -> Option<(Self, Self, Self)>;
+}
+
+macro_rules! impl_extended_gcd {
+    ($($t:ident($u:ident))*) => {
+        $(
+            impl ExtendedGcd for $t {
+                fn checked_extended_gcd(self, other: Self) -> Option<(Self, Self, Self)> {
+                    // Run the algorithm on the magnitudes in the unsigned type. The signs of
+                    // the coefficients alternate, so only their magnitudes are tracked,
+                    // which never exceed `|other| / g` and `|self| / g`.
+                    let (mut r0, mut r1) = (self.unsigned_abs(), other.unsigned_abs());
+                    let (mut s0, mut s1): ($u, $u) = (1, 0);
+                    let (mut t0, mut t1): ($u, $u) = (0, 1);
+                    let mut odd = false;
+                    while r1 != 0 {
+                        let q = r0 / r1;
+                        let r = r0 - q * r1;
+                        r0 = r1;
+                        r1 = r;
+                        let s = s0 + q * s1;
+                        s0 = s1;
+                        s1 = s;
+                        let t = t0 + q * t1;
+                        t0 = t1;
+                        t1 = t;
+                        odd = !odd;
+                    }
+                    // After `k` steps, `x` has the sign `(-1)^k` and `y` the opposite one,
+                    // each flipped again for a negative operand.
+                    let with_sign = |m: $u, negative: bool| if negative {
+                        <$t>::checked_sub_unsigned(0, m)
+                    } else {
+                        <$t>::checked_add_unsigned(0, m)
+                    };
+                    Some((with_sign(r0, false)?,
+                          with_sign(s0, odd != (self < 0))?,
+                          with_sign(t0, odd == (other < 0))?))
+                }
+            }
+        )*
+    }
+}
+
+impl_extended_gcd!(i8(u8) i16(u16) i32(u32) i64(u64) i128(u128) isize(usize));
+
+#[test]
+fn test_modular_ops() {
+    assert_eq!(ModularOps::mod_mul(u64::MAX - 1, u64::MAX - 2, u64::MAX), 2);
+    assert_eq!(ModularOps::mod_mul(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+    assert_eq!(ModularOps::mod_mul(1u128 << 100, 1u128 << 100, (1 << 127) - 1), 1 << 73);
+    assert_eq!(ModularOps::mod_add(250u8, 250, 251), 249);
+    assert_eq!(ModularOps::mod_add(u32::MAX, u32::MAX, u32::MAX), 0);
+    assert_eq!(ModularOps::mod_sub(3u16, 5, 7), 5);
+    assert_eq!(ModularOps::mod_sub(0u16, u16::MAX, u16::MAX), 0);
+
+    assert_eq!(ModularOps::mod_pow(2u64, 1_000_000_006, 1_000_000_007), 1);
+    assert_eq!(ModularOps::mod_pow(3u128, (1 << 127) - 2, (1 << 127) - 1), 1);
+    assert_eq!(ModularOps::mod_pow(5u8, 0, 1), 0);
+    assert_eq!(ModularOps::mod_pow(0usize, 0, 13), 1);
+
+    assert_eq!(ModularOps::mod_inv(3u32, 7), Some(5));
+    assert_eq!(ModularOps::mod_inv(10u32, 7), Some(5));
+    assert_eq!(ModularOps::mod_inv(2u32, 4), None);
+    assert_eq!(ModularOps::mod_inv(0u8, 1), Some(0));
+    let p = u64::MAX - 58;
+    let x = ModularOps::mod_inv(123_456_789u64, p).unwrap();
+    assert_eq!(ModularOps::mod_mul(x, 123_456_789, p), 1);
+}
+
+#[test]
+fn test_extended_gcd() {
+    assert_eq!(ExtendedGcd::extended_gcd(240i32, 46), (2, -9, 47));
+    assert_eq!(ExtendedGcd::extended_gcd(-240i64, 46), (2, 9, 47));
+    assert_eq!(ExtendedGcd::extended_gcd(0i8, -5), (5, 0, -1));
+    assert_eq!(ExtendedGcd::extended_gcd(0i8, 0), (0, 1, 0));
+    assert_eq!(ExtendedGcd::extended_gcd(1i8, i8::MIN), (1, 1, 0));
+    assert_eq!(ExtendedGcd::extended_gcd(i8::MIN, 1), (1, 0, 1));
+    assert_eq!(ExtendedGcd::extended_gcd(i8::MIN, -3), (1, 1, -43));
+    assert_eq!(ExtendedGcd::checked_extended_gcd(i8::MIN, 0), None);
+    assert_eq!(ExtendedGcd::checked_extended_gcd(i8::MIN, i8::MIN), None);
+    assert_eq!(ExtendedGcd::checked_extended_gcd(i64::MIN, i64::MIN / 2), Some((1 << 62, 0, -1)));
+    for &(a, b) in &[(12i128, 18), (-35, 15), (17, -5), (i64::MAX as i128, 1 << 62)] {
+        let (g, x, y) = ExtendedGcd::extended_gcd(a, b);
+        assert_eq!(g, Integer::gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            match ExtendedGcd::checked_extended_gcd(a, b) {
+                Some((g, x, y)) => {
+                    assert_eq!(Some(g), Integer::checked_gcd(a, b));
+                    assert_eq!(a as i32 * x as i32 + b as i32 * y as i32, g as i32);
+                    if g != 0 {
+                        assert!((x as i32).abs() <= (b as i32 / g as i32).abs().max(1));
+                        assert!((y as i32).abs() <= (a as i32 / g as i32).abs().max(1));
+                    }
+                }
+                None => assert_eq!(Integer::checked_gcd(a, b), None),
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::mem::size_of;
+
+use ::{Int, UnsignedInt};
+
+/// Exact integer roots.
+///
+/// Roots are rounded toward negative infinity, i.e. the result is the largest `r`
+/// such that `r.pow(n) <= self`, so `(-9).cbrt() == -3`.
+pub trait Roots: Int {
+    /// Returns the `n`th root of `self`, or `None` if `n` is zero or if `n` is even
+    /// and `self` is negative.
+    fn checked_nth_root(self, n: u32) -> Option<Self>;
+
+    /// Returns the `n`th root of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `n` is zero or if `n` is even and `self` is negative.
+    fn nth_root(self, n: u32) -> Self {
+        match self.checked_nth_root(n) {
+            Some(r) => r,
+            None if n == 0 => panic!("attempt to compute the zeroth root"),
+            None => panic!("attempt to compute an even root of a negative number"),
+        }
+    }
+
+    /// Returns the square root of `self`, or `None` if `self` is negative.
+    fn checked_sqrt(self) -> Option<Self> {
+        self.checked_nth_root(2)
+    }
+
+    /// Returns the square root of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is negative.
+    fn sqrt(self) -> Self {
+        self.nth_root(2)
+    }
+
+    /// Returns the cube root of `self`.
+    fn cbrt(self) -> Self {
+        self.nth_root(3)
+    }
+
+    /// Returns `true` if `self` is the square of an integer.
+    fn is_perfect_square(self) -> bool {
+        self.checked_sqrt().is_some_and(|r| r * r == self)
+    }
+}
+
+/// Computes the floor of the `n`th root bit by bit, from the highest bit the root can have.
+fn floor_root<U: UnsignedInt>(x: U, n: u32) -> U {
+    if n == 1 || x <= U::one() {
+        return x;
+    }
+    let len = (size_of::<U>() * 8) as u32 - x.leading_zeros();
+    let mut r = U::zero();
+    let mut bit = (len - 1) / n;
+    loop {
+        let candidate = r | U::one() << bit as usize;
+        if candidate.checked_pow(n).is_some_and(|p| p <= x) {
+            r = candidate;
+        }
+        if bit == 0 {
+            return r;
+        }
+        bit -= 1;
+    }
+}
+
+macro_rules! impl_roots_unsigned {
+    ($($t:ty)*) => {
+        $(
+            impl Roots for $t {
+                fn checked_nth_root(self, n: u32) -> Option<Self> {
+                    if n == 0 {
+                        return None;
+                    }
+                    Some(floor_root(self, n))
+                }
+            }
+        )*
+    }
+}
+
+impl_roots_unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_roots_signed {
+    ($($t:ty)*) => {
+        $(
+            impl Roots for $t {
+                fn checked_nth_root(self, n: u32) -> Option<Self> {
+                    if n == 0 || (self < 0 && n % 2 == 0) {
+                        return None;
+                    }
+                    if n == 1 {
+                        return Some(self);
+                    }
+                    // For `n >= 2` the root of `|self|` always fits in the signed type.
+                    let a = self.unsigned_abs();
+                    let r = floor_root(a, n);
+                    if self >= 0 {
+                        Some(r as $t)
+                    } else if Int::pow(r, n) == a {
+                        Some(-(r as $t))
+                    } else {
+                        Some(-(r as $t) - 1)
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_roots_signed!(i8 i16 i32 i64 i128 isize);
+
+#[test]
+fn test_roots_unsigned() {
+    assert_eq!(Roots::sqrt(0u8), 0);
+    assert_eq!(Roots::sqrt(255u8), 15);
+    assert_eq!(Roots::cbrt(255u8), 6);
+    assert_eq!(Roots::sqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(Roots::sqrt((1u64 << 53) + 1), 94_906_265);
+    assert_eq!(Roots::sqrt(u128::MAX), u64::MAX as u128);
+    assert_eq!(Roots::cbrt(u64::MAX), 2_642_245);
+    assert_eq!(Roots::nth_root(u128::MAX, 127), 2);
+    assert_eq!(Roots::nth_root(u32::MAX, 40), 1);
+    assert_eq!(Roots::nth_root(1_000_000_007usize, 1), 1_000_000_007);
+    assert_eq!(Roots::checked_nth_root(9u16, 0), None);
+
+    for x in 0..=u16::MAX {
+        let r = Roots::sqrt(x) as u32;
+        assert!(r * r <= x as u32 && (r + 1) * (r + 1) > x as u32);
+        assert_eq!(Roots::is_perfect_square(x), r * r == x as u32);
+    }
+}
+
+#[test]
+fn test_roots_signed() {
+    assert_eq!(Roots::sqrt(i8::MAX), 11);
+    assert_eq!(Roots::checked_sqrt(-4i32), None);
+    assert_eq!(Roots::checked_nth_root(-16i64, 4), None);
+    assert_eq!(Roots::cbrt(-9i32), -3);
+    assert_eq!(Roots::cbrt(-8i32), -2);
+    assert_eq!(Roots::cbrt(i64::MIN + 1), -2_097_152);
+    assert_eq!(Roots::cbrt(i64::MIN), -2_097_152);
+    assert_eq!(Roots::nth_root(i128::MIN, 127), -2);
+    assert_eq!(Roots::nth_root(i8::MIN, 1), i8::MIN);
+    assert_eq!(Roots::nth_root(i8::MIN, 7), -2);
+    assert!(Roots::is_perfect_square(1i16 << 14));
+    assert!(!Roots::is_perfect_square(-1isize));
+
+    for x in i16::MIN..=i16::MAX {
+        let r = Roots::cbrt(x) as i64;
+        assert!(r * r * r <= x as i64 && (r + 1) * (r + 1) * (r + 1) > x as i64);
+    }
+}
@@ -1,7 +1,9 @@
 
-use std::{f32, f64};
+use std::cmp::Ordering;
 use std::ops::Neg;
 
+use ::Num;
+
 /// Signed numbers.
 pub trait Signed: Sized + Neg<Output = Self> {
     /// Computes the absolute value.
@@ -9,8 +11,22 @@ pub trait Signed: Sized + Neg<Output = Self> {
     /// For `f32` and `f64`, `NaN` will be returned if the number is `NaN`.
     ///
     /// For signed integers, `::MIN` will be returned if the number is `::MIN`.
+    /// Use `checked_abs`, `saturating_abs` or `Int::unsigned_abs` to detect
+    /// or avoid this overflow.
     fn abs(&self) -> Self;
 
+    /// Checked absolute value. Computes `self.abs()`, returning `None`
+    /// for signed integers equal to `::MIN`.
+    ///
+    /// For `f32` and `f64`, this always returns `Some(self.abs())`.
+    fn checked_abs(&self) -> Option<Self>;
+
+    /// Wrapping absolute value. Computes `self.abs()`, wrapping around
+    /// to `::MIN` for signed integers equal to `::MIN`.
+    ///
+    /// For `f32` and `f64`, this is the same as `self.abs()`.
+    fn wrapping_abs(&self) -> Self;
+
     /// Returns the sign of the number.
     ///
     /// For `f32` and `f64`:
@@ -26,11 +42,83 @@ pub trait Signed: Sized + Neg<Output = Self> {
     /// * `-1` if the number is negative
     fn signum(&self) -> Self;
 
+    /// Returns the sign of the number as a `Sign`, or `None` if the number is `NaN`.
+    ///
+    /// Both `+0.0` and `-0.0` are `Sign::Zero`. For signed integers, this always
+    /// returns `Some`.
+    fn sign(&self) -> Option<Sign>;
+
     /// Returns true if the number is positive and false if the number is zero or negative.
     fn is_positive(&self) -> bool;
 
     /// Returns true if the number is negative and false if the number is zero or positive.
     fn is_negative(&self) -> bool;
+
+    /// Returns true if the sign of the number is positive.
+    ///
+    /// For `f32` and `f64`, this includes `+0.0`, `INFINITY`
+    /// and `NaN` with a positive sign bit.
+    ///
+    /// For signed integers, this includes zero.
+    fn is_sign_positive(&self) -> bool;
+
+    /// Returns true if the sign of the number is negative.
+    ///
+    /// For `f32` and `f64`, this includes `-0.0`, `NEG_INFINITY`
+    /// and `NaN` with a negative sign bit.
+    ///
+    /// For signed integers, this is the same as `is_negative`.
+    fn is_sign_negative(&self) -> bool;
+
+    /// Returns true if the number is zero or `NaN`, i.e. neither positive nor negative.
+    ///
+    /// For `f32` and `f64`, this includes both `+0.0` and `-0.0`.
+    fn is_nan_or_zero(&self) -> bool;
+
+    /// Saturating negation. Computes `-self`, returning `::MAX` instead of
+    /// overflowing for signed integers equal to `::MIN`.
+    ///
+    /// For `f32` and `f64`, this is the same as `-self`.
+    fn saturating_neg(&self) -> Self;
+
+    /// Saturating absolute value. Computes `self.abs()`, returning `::MAX` instead of
+    /// overflowing for signed integers equal to `::MIN`.
+    ///
+    /// For `f32` and `f64`, this is the same as `self.abs()`.
+    fn saturating_abs(&self) -> Self;
+}
+
+/// The sign of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sign {
+    /// The number is less than zero.
+    Negative,
+    /// The number is zero.
+    Zero,
+    /// The number is greater than zero.
+    Positive,
+}
+
+impl Sign {
+    fn of<T: PartialOrd>(x: T, zero: T) -> Option<Sign> {
+        x.partial_cmp(&zero).map(|ordering| match ordering {
+            Ordering::Less => Sign::Negative,
+            Ordering::Equal => Sign::Zero,
+            Ordering::Greater => Sign::Positive,
+        })
+    }
+}
+
+impl Neg for Sign {
+    type Output = Sign;
+
+    fn neg(self) -> Sign {
+        match self {
+            Sign::Negative => Sign::Positive,
+            Sign::Zero => Sign::Zero,
+            Sign::Positive => Sign::Negative,
+        }
+    }
 }
 
 macro_rules! impl_signed_int {
@@ -42,44 +130,190 @@ macro_rules! impl_signed_int {
                     <$t>::abs(*self)
                 }
 
+                #[inline]
+                fn checked_abs(&self) -> Option<$t> {
+                    <$t>::checked_abs(*self)
+                }
+
+                #[inline]
+                fn wrapping_abs(&self) -> $t {
+                    <$t>::wrapping_abs(*self)
+                }
+
                 #[inline]
                 fn signum(&self) -> $t {
                     <$t>::signum(*self)
                 }
 
+                #[inline]
+                fn sign(&self) -> Option<Sign> {
+                    Sign::of(*self, 0)
+                }
+
                 #[inline]
                 fn is_positive(&self) -> bool { *self > 0 }
 
                 #[inline]
                 fn is_negative(&self) -> bool { *self < 0 }
+
+                #[inline]
+                fn is_sign_positive(&self) -> bool { *self >= 0 }
+
+                #[inline]
+                fn is_sign_negative(&self) -> bool { *self < 0 }
+
+                #[inline]
+                fn is_nan_or_zero(&self) -> bool { *self == 0 }
+
+                #[inline]
+                fn saturating_neg(&self) -> $t {
+                    <$t>::saturating_neg(*self)
+                }
+
+                #[inline]
+                fn saturating_abs(&self) -> $t {
+                    <$t>::saturating_abs(*self)
+                }
             }
         )*
     }
 }
 
-impl_signed_int!(i8 i16 i32 i64 isize);
+impl_signed_int!(i8 i16 i32 i64 i128 isize);
 
 macro_rules! impl_signed_float {
-    ($t:ty, $inf:expr, $neg_inf:expr) => {
-        impl Signed for $t {
-            #[inline]
-            fn abs(&self) -> $t {
-                <$t>::abs(*self)
-            }
+    ($($t:ty)*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn abs(&self) -> $t {
+                    <$t>::abs(*self)
+                }
+
+                #[inline]
+                fn checked_abs(&self) -> Option<$t> {
+                    Some(<$t>::abs(*self))
+                }
+
+                #[inline]
+                fn wrapping_abs(&self) -> $t {
+                    <$t>::abs(*self)
+                }
+
+                #[inline]
+                fn signum(&self) -> $t {
+                    <$t>::signum(*self)
+                }
+
+                #[inline]
+                fn sign(&self) -> Option<Sign> {
+                    Sign::of(*self, 0.0)
+                }
+
+                #[inline]
+                fn is_positive(&self) -> bool { *self > 0.0 }
+
+                #[inline]
+                fn is_negative(&self) -> bool { *self < 0.0 }
+
+                #[inline]
+                fn is_sign_positive(&self) -> bool {
+                    <$t>::is_sign_positive(*self)
+                }
+
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    <$t>::is_sign_negative(*self)
+                }
+
+                #[inline]
+                fn is_nan_or_zero(&self) -> bool {
+                    <$t>::is_nan(*self) || *self == 0.0
+                }
 
-            #[inline]
-            fn signum(&self) -> $t {
-                <$t>::signum(*self)
+                #[inline]
+                fn saturating_neg(&self) -> $t {
+                    -*self
+                }
+
+                #[inline]
+                fn saturating_abs(&self) -> $t {
+                    <$t>::abs(*self)
+                }
             }
+        )*
+    }
+}
 
-            #[inline]
-            fn is_positive(&self) -> bool { *self > 0.0 || (1.0 / *self) == $inf }
+impl_signed_float!(f32 f64);
 
-            #[inline]
-            fn is_negative(&self) -> bool { *self < 0.0 || (1.0 / *self) == $neg_inf }
-        }
+/// Unsigned numbers.
+///
+/// This is a marker trait for numbers which cannot be negative.
+pub trait Unsigned: Num {}
+
+macro_rules! impl_unsigned {
+    ($($t:ty)*) => {
+        $(
+            impl Unsigned for $t {}
+        )*
     }
 }
 
-impl_signed_float!(f32, f32::INFINITY, f32::NEG_INFINITY);
-impl_signed_float!(f64, f64::INFINITY, f64::NEG_INFINITY);
+impl_unsigned!(u8 u16 u32 u64 u128 usize);
+
+#[test]
+fn test_saturating_signed() {
+    assert_eq!(Signed::saturating_neg(&i8::MIN), i8::MAX);
+    assert_eq!(Signed::saturating_neg(&5i64), -5);
+    assert_eq!(Signed::saturating_abs(&i32::MIN), i32::MAX);
+    assert_eq!(Signed::saturating_abs(&-7isize), 7);
+    assert_eq!(Signed::saturating_neg(&f32::MAX), f32::MIN);
+    assert_eq!(Signed::saturating_abs(&f64::NEG_INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_abs_and_sign() {
+    assert_eq!(Signed::checked_abs(&i8::MIN), None);
+    assert_eq!(Signed::checked_abs(&-5i128), Some(5));
+    assert_eq!(Signed::checked_abs(&-0.5f32), Some(0.5));
+    assert_eq!(Signed::wrapping_abs(&i16::MIN), i16::MIN);
+    assert_eq!(Signed::wrapping_abs(&-3isize), 3);
+
+    assert_eq!(Signed::sign(&-7i32), Some(Sign::Negative));
+    assert_eq!(Signed::sign(&0i64), Some(Sign::Zero));
+    assert_eq!(Signed::sign(&i8::MAX), Some(Sign::Positive));
+    assert_eq!(Signed::sign(&-0.0f64), Some(Sign::Zero));
+    assert_eq!(Signed::sign(&f32::NEG_INFINITY), Some(Sign::Negative));
+    assert_eq!(Signed::sign(&1.0e-40f32), Some(Sign::Positive));
+    assert_eq!(Signed::sign(&f64::NAN), None);
+    assert_eq!(-Sign::Negative, Sign::Positive);
+    assert!(Sign::Negative < Sign::Zero && Sign::Zero < Sign::Positive);
+
+    assert_eq!(::Int::abs_diff(-128i8, 127), 255u8);
+    assert_eq!(::Int::abs_diff(3u16, 10), 7);
+    assert_eq!(::Int::abs_diff(i64::MAX, i64::MIN), u64::MAX);
+}
+
+#[test]
+fn test_sign_predicates() {
+    fn count_positive<T: Signed>(xs: &[T]) -> usize {
+        xs.iter().filter(|x| x.is_positive()).count()
+    }
+
+    assert_eq!(count_positive(&[0.0f64, -0.0, 1.0, f64::NAN, f64::INFINITY]), 2);
+    assert_eq!(count_positive(&[0i32, -1, 1, i32::MAX]), 2);
+    assert!(!Signed::is_negative(&-0.0f32));
+
+    assert!(Signed::is_sign_positive(&0.0f64));
+    assert!(Signed::is_sign_negative(&-0.0f64));
+    assert!(Signed::is_sign_positive(&0i8));
+    assert!(!Signed::is_sign_negative(&0i8));
+    assert!(Signed::is_sign_negative(&-1i64));
+
+    assert!(Signed::is_nan_or_zero(&f32::NAN));
+    assert!(Signed::is_nan_or_zero(&-0.0f32));
+    assert!(!Signed::is_nan_or_zero(&f32::MIN_POSITIVE));
+    assert!(Signed::is_nan_or_zero(&0i16));
+    assert!(!Signed::is_nan_or_zero(&-1i16));
+}
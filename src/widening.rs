@@ -0,0 +1,111 @@
+use ::UnsignedInt;
+
+/// Widening and carrying arithmetic on unsigned integers, the building blocks
+/// of multi-precision integers.
+pub trait WideningOps: UnsignedInt {
+    /// The unsigned integer type of twice the width, e.g. `u64` for `u32`.
+    type Wide: UnsignedInt;
+
+    /// Converts `self` into the double-width type without loss.
+    fn widen(self) -> Self::Wide;
+
+    /// Splits a double-width value into its low and high halves.
+    fn split_wide(wide: Self::Wide) -> (Self, Self);
+
+    /// Calculates the complete product `self * rhs` without the possibility to overflow.
+    ///
+    /// Returns the low-order (wrapping) bits and the high-order (overflow) bits
+    /// of the result as two separate values, in that order.
+    fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        Self::split_wide(self.widen() * rhs.widen())
+    }
+
+    /// Calculates the "full multiplication" `self * rhs + carry`
+    /// without the possibility to overflow.
+    ///
+    /// Returns the low-order (wrapping) bits and the high-order (overflow) bits
+    /// of the result as two separate values, in that order.
+    fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+        Self::split_wide(self.widen() * rhs.widen() + carry.widen())
+    }
+
+    /// Calculates `self + rhs + carry` and returns a tuple containing
+    /// the sum and the output carry.
+    fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let (a, c1) = self.overflowing_add(rhs);
+        let (b, c2) = a.overflowing_add(if carry { Self::one() } else { Self::zero() });
+        (b, c1 || c2)
+    }
+
+    /// Calculates `self - rhs - borrow` and returns a tuple containing
+    /// the difference and the output borrow.
+    fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let (a, b1) = self.overflowing_sub(rhs);
+        let (b, b2) = a.overflowing_sub(if borrow { Self::one() } else { Self::zero() });
+        (b, b1 || b2)
+    }
+}
+
+macro_rules! impl_widening {
+    ($($t:ident($w:ident))*) => {
+        $(
+            impl WideningOps for $t {
+                type Wide = $w;
+
+                #[inline]
+                fn widen(self) -> $w {
+                    self as $w
+                }
+
+                #[inline]
+                fn split_wide(wide: $w) -> ($t, $t) {
+                    (wide as $t, (wide >> <$t>::BITS) as $t)
+                }
+            }
+        )*
+    }
+}
+
+impl_widening!(u8(u16) u16(u32) u32(u64) u64(u128));
+
+#[cfg(target_pointer_width = "16")]
+impl_widening!(usize(u32));
+#[cfg(target_pointer_width = "32")]
+impl_widening!(usize(u64));
+#[cfg(target_pointer_width = "64")]
+impl_widening!(usize(u128));
+
+#[test]
+fn test_widening_ops() {
+    fn add_limbs<T: WideningOps>(a: &[T], b: &[T]) -> (Vec<T>, bool) {
+        let mut carry = false;
+        let sum = a.iter().zip(b).map(|(&x, &y)| {
+            let (s, c) = x.carrying_add(y, carry);
+            carry = c;
+            s
+        }).collect();
+        (sum, carry)
+    }
+
+    fn mul_limbs<T: WideningOps>(a: &[T], b: T) -> Vec<T> {
+        let mut carry = T::zero();
+        let mut product: Vec<T> = a.iter().map(|&x| {
+            let (lo, hi) = x.carrying_mul(b, carry);
+            carry = hi;
+            lo
+        }).collect();
+        product.push(carry);
+        product
+    }
+
+    assert_eq!(add_limbs(&[u32::MAX, 0], &[1, 0]), (vec![0, 1], false));
+    assert_eq!(add_limbs(&[u8::MAX, u8::MAX], &[1, 0]), (vec![0, 0], true));
+    assert_eq!(mul_limbs(&[u64::MAX, u64::MAX], u64::MAX), vec![1, u64::MAX, u64::MAX - 1]);
+    assert_eq!(mul_limbs(&[0x80u8], 2), vec![0, 1]);
+
+    assert_eq!(WideningOps::widening_mul(u16::MAX, u16::MAX), (1, u16::MAX - 1));
+    assert_eq!(WideningOps::widening_mul(usize::MAX, 2), (usize::MAX - 1, 1));
+    assert_eq!(WideningOps::carrying_mul(u8::MAX, u8::MAX, u8::MAX), (0, u8::MAX));
+    assert_eq!(WideningOps::borrowing_sub(0u32, 0, true), (u32::MAX, true));
+    assert_eq!(WideningOps::borrowing_sub(5u32, 3, true), (1, false));
+}